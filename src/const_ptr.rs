@@ -0,0 +1,421 @@
+use core::{cmp::Ordering, fmt, hash, marker::PhantomData, num::NonZeroUsize, ptr::NonNull};
+
+use crate::NonNullMut;
+
+/// Like [`NonNull<T>`], but for read-only access like `*const T`
+///
+/// `T` is covariant like `*const T`, the mutable counterpart is [`NonNullMut<T>`]
+///
+/// # Examples
+///
+/// ```
+/// use nonnull_mut::NonNullConst;
+///
+/// fn shorten<'a>(ptr: NonNullConst<&'static str>) -> NonNullConst<&'a str> {
+///     ptr
+/// }
+///
+/// let s = "foo";
+/// let ptr = shorten(NonNullConst::from(&s));
+/// assert_eq!(unsafe { ptr.read() }, "foo");
+/// ```
+///
+/// [`NonNullMut<T>`] is invariant, so the same code does not compile
+///
+/// ```compile_fail
+/// use nonnull_mut::NonNullMut;
+///
+/// fn shorten<'a>(ptr: NonNullMut<&'static str>) -> NonNullMut<&'a str> {
+///     ptr
+/// }
+/// ```
+#[repr(transparent)]
+pub struct NonNullConst<T: ?Sized> {
+    inner: NonNull<T>,
+    _phantom: PhantomData<*const T>,
+}
+
+impl<T: ?Sized> From<NonNull<T>> for NonNullConst<T> {
+    fn from(inner: NonNull<T>) -> Self {
+        Self::from_inner(inner)
+    }
+}
+
+impl<T: ?Sized> From<NonNullConst<T>> for NonNull<T> {
+    fn from(value: NonNullConst<T>) -> Self {
+        value.inner
+    }
+}
+
+/// Demote a mutable pointer into a read-only pointer
+///
+/// # Examples
+///
+/// ```
+/// use nonnull_mut::{NonNullConst, NonNullMut};
+///
+/// let mut x = 2u32;
+/// let ptr = NonNullMut::from(&mut x);
+/// let ptr = NonNullConst::from(ptr);
+/// assert_eq!(unsafe { ptr.read() }, 2);
+/// ```
+impl<T: ?Sized> From<NonNullMut<T>> for NonNullConst<T> {
+    fn from(value: NonNullMut<T>) -> Self {
+        Self::from_inner(value.inner)
+    }
+}
+
+impl<T> NonNullConst<T> {
+    /// Like [`NonNull::dangling`]
+    #[inline]
+    #[must_use]
+    pub const fn dangling() -> Self {
+        Self::from_inner(NonNull::dangling())
+    }
+}
+
+impl<T: ?Sized> NonNullConst<T> {
+    /// Like [`NonNull::new`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullConst;
+    ///
+    /// let x = 0u32;
+    /// let ptr = NonNullConst::<u32>::new(&x as *const _).expect("ptr is null!");
+    ///
+    /// if let Some(ptr) = NonNullConst::<u32>::new(std::ptr::null()) {
+    ///     unreachable!();
+    /// }
+    /// ```
+    #[inline]
+    pub const fn new(ptr: *const T) -> Option<Self> {
+        match NonNull::new(ptr.cast_mut()) {
+            Some(inner) => Some(Self::from_inner(inner)),
+            None => None,
+        }
+    }
+
+    /// Like [`NonNull::new_unchecked`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::new_unchecked`] for safety concerns and examples.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn new_unchecked(ptr: *const T) -> Self {
+        Self::from_inner(unsafe { NonNull::new_unchecked(ptr.cast_mut()) })
+    }
+
+    /// Create [`NonNullConst<T>`] from [`NonNull<T>`]
+    #[must_use]
+    pub const fn from_inner(inner: NonNull<T>) -> Self {
+        Self {
+            inner,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Like [`NonNull::addr`]
+    #[inline]
+    #[must_use]
+    pub fn addr(self) -> NonZeroUsize {
+        self.inner.addr()
+    }
+
+    /// Like [`NonNull::as_ptr`], but returns `*const T`
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_ptr(self) -> *const T {
+        self.inner.as_ptr().cast_const()
+    }
+
+    /// Get inner [`NonNull<T>`]
+    #[must_use]
+    pub const fn as_inner(self) -> NonNull<T> {
+        self.inner
+    }
+
+    /// Like [`NonNull::as_ref`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::as_ref`] for safety concerns and examples.
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn as_ref<'a>(&self) -> &'a T {
+        unsafe { self.inner.as_ref() }
+    }
+
+    /// Like [`NonNull::cast`]
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn cast<U>(self) -> NonNullConst<U> {
+        NonNullConst::from_inner(self.inner.cast())
+    }
+
+    /// Like [`NonNull::offset`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::offset`] for safety concerns and examples.
+    #[inline(always)]
+    #[must_use = "returns a new pointer rather than modifying its argument"]
+    pub const unsafe fn offset(self, count: isize) -> Self
+    where
+        T: Sized,
+    {
+        unsafe { Self::from_inner(self.inner.offset(count)) }
+    }
+
+    /// Like [`NonNull::byte_offset`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::byte_offset`] for safety concerns and examples.
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn byte_offset(self, count: isize) -> Self {
+        unsafe { Self::from_inner(self.inner.byte_offset(count)) }
+    }
+
+    /// Like [`NonNull::add`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::add`] for safety concerns and examples.
+    #[inline(always)]
+    #[must_use = "returns a new pointer rather than modifying its argument"]
+    pub const unsafe fn add(self, count: usize) -> Self
+    where
+        T: Sized,
+    {
+        unsafe { Self::from_inner(self.inner.add(count)) }
+    }
+
+    /// Like [`NonNull::byte_add`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::byte_add`] for safety concerns and examples.
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn byte_add(self, count: usize) -> Self {
+        unsafe { Self::from_inner(self.inner.byte_add(count)) }
+    }
+
+    /// Like [`NonNull::sub`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::sub`] for safety concerns and examples.
+    #[inline(always)]
+    #[must_use = "returns a new pointer rather than modifying its argument"]
+    pub const unsafe fn sub(self, count: usize) -> Self
+    where
+        T: Sized,
+    {
+        unsafe { Self::from_inner(self.inner.sub(count)) }
+    }
+
+    /// Like [`NonNull::byte_sub`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::byte_sub`] for safety concerns and examples.
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn byte_sub(self, count: usize) -> Self {
+        unsafe { Self::from_inner(self.inner.byte_sub(count)) }
+    }
+
+    /// Like [`NonNull::offset_from`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::offset_from`] for safety concerns and examples.
+    #[inline]
+    pub const unsafe fn offset_from(self, origin: NonNullConst<T>) -> isize
+    where
+        T: Sized,
+    {
+        unsafe { self.inner.offset_from(origin.inner) }
+    }
+
+    /// Like [`NonNull::byte_offset_from`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::byte_offset_from`] for safety concerns and examples.
+    #[inline(always)]
+    pub const unsafe fn byte_offset_from<U: ?Sized>(self, origin: NonNullConst<U>) -> isize {
+        unsafe { self.inner.byte_offset_from(origin.inner) }
+    }
+
+    /// Like [`NonNull::read`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::read`] for safety concerns and examples.
+    #[inline]
+    pub const unsafe fn read(self) -> T
+    where
+        T: Sized,
+    {
+        unsafe { self.inner.read() }
+    }
+
+    /// Like [`NonNull::read_volatile`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::read_volatile`] for safety concerns and examples.
+    #[inline]
+    pub unsafe fn read_volatile(self) -> T
+    where
+        T: Sized,
+    {
+        unsafe { self.inner.read_volatile() }
+    }
+
+    /// Like [`NonNull::read_unaligned`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::read_unaligned`] for safety concerns and examples.
+    #[inline]
+    pub const unsafe fn read_unaligned(self) -> T
+    where
+        T: Sized,
+    {
+        unsafe { self.inner.read_unaligned() }
+    }
+
+    /// Like [`NonNull::align_offset`]
+    #[inline]
+    #[must_use]
+    pub fn align_offset(self, align: usize) -> usize
+    where
+        T: Sized,
+    {
+        self.inner.align_offset(align)
+    }
+
+    /// Like [`NonNull::is_aligned`]
+    #[inline]
+    #[must_use]
+    pub fn is_aligned(self) -> bool
+    where
+        T: Sized,
+    {
+        self.inner.is_aligned()
+    }
+}
+
+impl<T> NonNullConst<[T]> {
+    /// Like [`NonNull::slice_from_raw_parts`]
+    #[inline]
+    #[must_use]
+    pub const fn slice_from_raw_parts(data: NonNullConst<T>, len: usize) -> Self {
+        Self::from_inner(NonNull::slice_from_raw_parts(data.inner, len))
+    }
+
+    /// Like [`NonNull::len`]
+    #[inline]
+    #[must_use]
+    pub const fn len(self) -> usize {
+        self.inner.len()
+    }
+
+    /// Like [`NonNull::is_empty`]
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl<T: ?Sized> Clone for NonNullConst<T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for NonNullConst<T> {}
+
+impl<T: ?Sized> fmt::Debug for NonNullConst<T> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.as_ptr(), f)
+    }
+}
+
+impl<T: ?Sized> fmt::Pointer for NonNullConst<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.as_ptr(), f)
+    }
+}
+
+impl<T: ?Sized> Eq for NonNullConst<T> {}
+
+#[allow(ambiguous_wide_pointer_comparisons)]
+impl<T: ?Sized> PartialEq for NonNullConst<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ptr() == other.as_ptr()
+    }
+}
+
+#[allow(ambiguous_wide_pointer_comparisons)]
+impl<T: ?Sized> Ord for NonNullConst<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_ptr().cmp(&other.as_ptr())
+    }
+}
+
+#[allow(ambiguous_wide_pointer_comparisons)]
+#[allow(clippy::non_canonical_partial_ord_impl)]
+impl<T: ?Sized> PartialOrd for NonNullConst<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_ptr().partial_cmp(&other.as_ptr())
+    }
+}
+
+#[allow(ambiguous_wide_pointer_comparisons)]
+impl<T: ?Sized> hash::Hash for NonNullConst<T> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.as_ptr().hash(state)
+    }
+}
+
+impl<T: ?Sized> From<&T> for NonNullConst<T> {
+    #[inline]
+    fn from(r: &T) -> Self {
+        NonNullConst::from_inner(r.into())
+    }
+}
+
+impl<T: ?Sized> From<NonNullConst<T>> for *const T {
+    #[inline]
+    fn from(value: NonNullConst<T>) -> Self {
+        value.as_ptr()
+    }
+}
+
+/// Unlike `*const T`, this is [`Send`] with the `send-sync` feature,
+/// the caller is responsible for synchronizing access to the pointee
+/// when sending the pointer to another thread.
+#[cfg(feature = "send-sync")]
+unsafe impl<T: ?Sized> Send for NonNullConst<T> {}
+
+/// Unlike `*const T`, this is [`Sync`] with the `send-sync` feature,
+/// the caller is responsible for synchronizing access to the pointee
+/// when sharing the pointer between threads.
+#[cfg(feature = "send-sync")]
+unsafe impl<T: ?Sized> Sync for NonNullConst<T> {}
@@ -0,0 +1,83 @@
+use core::{fmt, mem};
+
+use crate::NonNullMut;
+
+/// A scope guard that calls [`NonNullMut::drop_in_place`] when dropped
+///
+/// Use [`DropGuard::defuse`] to cancel the drop
+///
+/// # Examples
+///
+/// ```
+/// use nonnull_mut::{DropGuard, NonNullMut};
+/// use std::{cell::Cell, mem::MaybeUninit};
+///
+/// struct Counter<'a>(&'a Cell<u32>);
+///
+/// impl Drop for Counter<'_> {
+///     fn drop(&mut self) {
+///         self.0.set(self.0.get() + 1);
+///     }
+/// }
+///
+/// let count = Cell::new(0);
+/// let mut slot = MaybeUninit::new(Counter(&count));
+/// let ptr = NonNullMut::from(&mut slot).cast::<Counter>().into();
+///
+/// {
+///     let _guard = unsafe { DropGuard::new(ptr) };
+///     assert_eq!(count.get(), 0);
+/// }
+/// assert_eq!(count.get(), 1);
+///
+/// slot.write(Counter(&count));
+/// let guard = unsafe { DropGuard::new(ptr) };
+/// assert_eq!(guard.defuse(), ptr);
+/// assert_eq!(count.get(), 1);
+/// ```
+#[must_use = "if unused the value will immediately be dropped"]
+pub struct DropGuard<T: ?Sized> {
+    ptr: NonNullMut<T>,
+}
+
+impl<T: ?Sized> DropGuard<T> {
+    /// Create a guard that drops the value pointed to by `ptr` when the guard is dropped
+    ///
+    /// # Safety
+    ///
+    /// When the guard is dropped,
+    /// `ptr` must be valid for [`NonNullMut::drop_in_place`],
+    /// see [`NonNull::drop_in_place`](core::ptr::NonNull::drop_in_place).
+    #[inline]
+    pub const unsafe fn new(ptr: NonNullMut<T>) -> Self {
+        Self { ptr }
+    }
+
+    /// Returns the guarded pointer
+    #[inline]
+    #[must_use]
+    pub const fn as_ptr(&self) -> NonNullMut<T> {
+        self.ptr
+    }
+
+    /// Cancel the drop, and returns the guarded pointer
+    #[inline]
+    pub const fn defuse(self) -> NonNullMut<T> {
+        let ptr = self.ptr;
+        mem::forget(self);
+        ptr
+    }
+}
+
+impl<T: ?Sized> Drop for DropGuard<T> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { self.ptr.drop_in_place() }
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for DropGuard<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DropGuard").field("ptr", &self.ptr).finish()
+    }
+}
@@ -0,0 +1,167 @@
+use core::{fmt, iter::FusedIterator};
+
+use crate::NonNullMut;
+
+fn elem_ptr<T>(data: NonNullMut<T>, index: usize) -> NonNullMut<T> {
+    data.map_ptr(|ptr| ptr.wrapping_add(index))
+        .expect("element pointer address overflow")
+}
+
+/// Iterator over the element pointers of a slice pointer
+///
+/// Created by [`NonNullMut::iter_ptrs`]
+pub struct ElemPtrs<T> {
+    data: NonNullMut<T>,
+    start: usize,
+    end: usize,
+}
+
+impl<T> ElemPtrs<T> {
+    pub(crate) fn new(slice: NonNullMut<[T]>) -> Self {
+        Self {
+            data: slice.as_non_null_ptr(),
+            start: 0,
+            end: slice.len(),
+        }
+    }
+
+    fn get(&self, index: usize) -> NonNullMut<T> {
+        elem_ptr(self.data, index)
+    }
+}
+
+impl<T> Clone for ElemPtrs<T> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<T> fmt::Debug for ElemPtrs<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ElemPtrs")
+            .field("data", &self.data)
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .finish()
+    }
+}
+
+impl<T> Iterator for ElemPtrs<T> {
+    type Item = NonNullMut<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        let elem = self.get(self.start);
+        self.start += 1;
+        Some(elem)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for ElemPtrs<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(self.get(self.end))
+    }
+}
+
+impl<T> ExactSizeIterator for ElemPtrs<T> {}
+
+impl<T> FusedIterator for ElemPtrs<T> {}
+
+/// Iterator over `chunk_len` elements subslice pointers of a slice pointer,
+/// the last chunk may be shorter
+///
+/// Created by [`NonNullMut::chunks_ptr`]
+pub struct ChunkPtrs<T> {
+    data: NonNullMut<T>,
+    start: usize,
+    end: usize,
+    chunk_len: usize,
+}
+
+impl<T> ChunkPtrs<T> {
+    #[track_caller]
+    pub(crate) fn new(slice: NonNullMut<[T]>, chunk_len: usize) -> Self {
+        assert!(chunk_len != 0, "chunk size must be non-zero");
+        Self {
+            data: slice.as_non_null_ptr(),
+            start: 0,
+            end: slice.len(),
+            chunk_len,
+        }
+    }
+
+    fn get(&self, index: usize, len: usize) -> NonNullMut<[T]> {
+        NonNullMut::slice_from_raw_parts(elem_ptr(self.data, index), len)
+    }
+}
+
+impl<T> Clone for ChunkPtrs<T> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<T> fmt::Debug for ChunkPtrs<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunkPtrs")
+            .field("data", &self.data)
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .field("chunk_len", &self.chunk_len)
+            .finish()
+    }
+}
+
+impl<T> Iterator for ChunkPtrs<T> {
+    type Item = NonNullMut<[T]>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        let len = self.chunk_len.min(self.end - self.start);
+        let chunk = self.get(self.start, len);
+        self.start += len;
+        Some(chunk)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.end - self.start).div_ceil(self.chunk_len);
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for ChunkPtrs<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        let len = match (self.end - self.start) % self.chunk_len {
+            0 => self.chunk_len,
+            rem => rem,
+        };
+        self.end -= len;
+        Some(self.get(self.end, len))
+    }
+}
+
+impl<T> ExactSizeIterator for ChunkPtrs<T> {}
+
+impl<T> FusedIterator for ChunkPtrs<T> {}
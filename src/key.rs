@@ -0,0 +1,129 @@
+use core::{fmt, hash, ptr};
+
+use crate::NonNullMut;
+
+/// Compare and hash [`NonNullMut<T>`] by the address only, ignoring the metadata
+///
+/// Slice pointers with the same address but different lengths are equal
+///
+/// # Examples
+///
+/// ```
+/// use nonnull_mut::{ByAddr, NonNullMut};
+/// use std::collections::HashSet;
+///
+/// let mut a = [0u8; 4];
+/// let ptr = NonNullMut::<[u8]>::from(&mut a[..]);
+/// let (head, _) = unsafe { ptr.split_at_mut_unchecked(2) };
+/// assert_ne!(ptr, head);
+///
+/// let set = HashSet::from([ByAddr(ptr), ByAddr(head)]);
+/// assert_eq!(set.len(), 1);
+/// ```
+#[repr(transparent)]
+pub struct ByAddr<T: ?Sized>(pub NonNullMut<T>);
+
+impl<T: ?Sized> Clone for ByAddr<T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for ByAddr<T> {}
+
+impl<T: ?Sized> fmt::Debug for ByAddr<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ByAddr").field(&self.0).finish()
+    }
+}
+
+impl<T: ?Sized> Eq for ByAddr<T> {}
+
+impl<T: ?Sized> PartialEq for ByAddr<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.addr() == other.0.addr()
+    }
+}
+
+impl<T: ?Sized> hash::Hash for ByAddr<T> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.0.addr().hash(state)
+    }
+}
+
+impl<T: ?Sized> From<NonNullMut<T>> for ByAddr<T> {
+    #[inline]
+    fn from(value: NonNullMut<T>) -> Self {
+        Self(value)
+    }
+}
+
+/// Compare and hash [`NonNullMut<T>`] by both the address and the metadata,
+/// like [`NonNullMut::eq_with_metadata`]
+///
+/// This behaves the same as the [`PartialEq`] and [`Hash`](hash::Hash) of [`NonNullMut<T>`] itself,
+/// it only exists for symmetry with [`ByAddr`]
+///
+/// Slice pointers with the same address but different lengths are distinct,
+/// trait object pointers are also compared by the vtable,
+/// which is not guaranteed to be unique for the same type
+///
+/// # Examples
+///
+/// ```
+/// use nonnull_mut::{FullKey, NonNullMut};
+/// use std::collections::HashSet;
+///
+/// let mut a = [0u8; 4];
+/// let ptr = NonNullMut::<[u8]>::from(&mut a[..]);
+/// let (head, _) = unsafe { ptr.split_at_mut_unchecked(2) };
+///
+/// let set = HashSet::from([FullKey(ptr), FullKey(head), FullKey(ptr)]);
+/// assert_eq!(set.len(), 2);
+/// assert!(set.contains(&FullKey(head)));
+///
+/// assert_eq!(FullKey(ptr) == FullKey(head), ptr == head);
+/// ```
+#[repr(transparent)]
+pub struct FullKey<T: ?Sized>(pub NonNullMut<T>);
+
+impl<T: ?Sized> Clone for FullKey<T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for FullKey<T> {}
+
+impl<T: ?Sized> fmt::Debug for FullKey<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FullKey").field(&self.0).finish()
+    }
+}
+
+impl<T: ?Sized> Eq for FullKey<T> {}
+
+impl<T: ?Sized> PartialEq for FullKey<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.0.as_ptr(), other.0.as_ptr())
+    }
+}
+
+impl<T: ?Sized> hash::Hash for FullKey<T> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.0.as_ptr().hash(state)
+    }
+}
+
+impl<T: ?Sized> From<NonNullMut<T>> for FullKey<T> {
+    #[inline]
+    fn from(value: NonNullMut<T>) -> Self {
+        Self(value)
+    }
+}
@@ -1,8 +1,106 @@
 #![doc = include_str!("../README.md")]
 #![no_std]
 #![forbid(unsafe_op_in_unsafe_fn)]
+#![cfg_attr(feature = "ptr-metadata", feature(ptr_metadata))]
+#![cfg_attr(feature = "slice-ptr-get", feature(slice_ptr_get))]
+#![cfg_attr(
+    feature = "coerce-unsized",
+    feature(coerce_unsized, dispatch_from_dyn, unsize)
+)]
 
-use core::{cmp::Ordering, fmt, hash, marker::PhantomData, num::NonZeroUsize, ptr::NonNull};
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::{
+    borrow::Borrow,
+    cell::Cell,
+    cmp::Ordering,
+    ffi::{c_char, CStr},
+    fmt, hash,
+    marker::PhantomData,
+    mem::{self, MaybeUninit},
+    num::NonZeroUsize,
+    ops::Range,
+    pin::Pin,
+    ptr::NonNull,
+    slice,
+    sync::atomic::{self, compiler_fence},
+};
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    alloc::{alloc, dealloc, handle_alloc_error},
+    boxed::Box,
+};
+#[cfg(feature = "alloc")]
+use core::alloc::Layout;
+#[cfg(feature = "ptr-metadata")]
+use core::ptr::{DynMetadata, Pointee};
+#[cfg(feature = "slice-ptr-get")]
+use core::slice::SliceIndex;
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::AtomicPtr;
+#[cfg(feature = "coerce-unsized")]
+use core::{
+    marker::Unsize,
+    ops::{CoerceUnsized, DispatchFromDyn},
+};
+
+mod const_ptr;
+mod guard;
+mod iter;
+mod key;
+mod traits;
+
+/// Write `len` zero bytes by volatile writes, then fence the compiler
+unsafe fn volatile_zero_bytes(ptr: NonNullMut<u8>, len: usize) {
+    for i in 0..len {
+        unsafe { ptr.add(i).write_volatile(0) }
+    }
+    compiler_fence(atomic::Ordering::SeqCst);
+}
+
+/// Create a [`NonNullMut`] from a place expression,
+/// by [`core::ptr::addr_of_mut`] without creating an intermediate reference
+///
+/// Can be used in `const` and `static` initializers
+///
+/// The pointer aliases the place,
+/// accessing the place while writing through the pointer (or vice versa)
+/// is still subject to the usual aliasing rules,
+/// the macro's internal `unsafe` only asserts that the address is non-null
+///
+/// # Examples
+///
+/// ```
+/// use nonnull_mut::{nonnull_mut, NonNullMut};
+///
+/// static mut A: u32 = 1;
+/// static mut B: u32 = 2;
+/// static mut TABLE: [NonNullMut<u32>; 2] = [nonnull_mut!(A), nonnull_mut!(B)];
+///
+/// let table = unsafe { TABLE };
+/// unsafe { table[1].write(table[0].read() + 2) };
+/// assert_eq!(unsafe { B }, 3);
+///
+/// let mut x = [1u32, 2];
+/// let ptr = nonnull_mut!(x[1]);
+/// unsafe { ptr.write(3) };
+/// assert_eq!(x, [1, 3]);
+/// ```
+#[macro_export]
+macro_rules! nonnull_mut {
+    ($place:expr) => {{
+        let ptr = ::core::ptr::addr_of_mut!($place);
+        unsafe { $crate::NonNullMut::new_unchecked(ptr) }
+    }};
+}
+
+pub use const_ptr::NonNullConst;
+pub use guard::DropGuard;
+pub use iter::{ChunkPtrs, ElemPtrs};
+pub use key::{ByAddr, FullKey};
+pub use traits::AsNonNull;
 
 #[doc = include_str!("../README.md")]
 #[repr(transparent)]
@@ -26,6 +124,87 @@ impl<T: ?Sized> From<NonNullMut<T>> for NonNull<T> {
     }
 }
 
+/// # Examples
+///
+/// ```
+/// use nonnull_mut::NonNullMut;
+///
+/// unsafe fn set(ptr: *mut u32) {
+///     unsafe { *ptr = 2 }
+/// }
+/// unsafe fn get(ptr: *const u32) -> u32 {
+///     unsafe { *ptr }
+/// }
+///
+/// let mut x = 0u32;
+/// let ptr = NonNullMut::from(&mut x);
+///
+/// unsafe {
+///     set(ptr.into());
+///     assert_eq!(get(ptr.into()), 2);
+/// }
+/// ```
+impl<T: ?Sized> From<NonNullMut<T>> for *mut T {
+    #[inline]
+    fn from(value: NonNullMut<T>) -> Self {
+        value.as_ptr()
+    }
+}
+
+impl<T: ?Sized> From<NonNullMut<T>> for *const T {
+    #[inline]
+    fn from(value: NonNullMut<T>) -> Self {
+        value.as_ptr()
+    }
+}
+
+/// The error type returned when converting a null pointer to [`NonNullMut<T>`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct NullPtrError;
+
+impl fmt::Display for NullPtrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("pointer is null")
+    }
+}
+
+impl core::error::Error for NullPtrError {}
+
+/// Display an address as lowercase hex with a `0x` prefix
+///
+/// Created by [`NonNullMut::addr_hex`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AddrHex(NonZeroUsize);
+
+impl fmt::Display for AddrHex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self.0)
+    }
+}
+
+/// Like [`NonNullMut::new`], but returns [`NullPtrError`] on null pointer
+///
+/// # Examples
+///
+/// ```
+/// use nonnull_mut::{NonNullMut, NullPtrError};
+///
+/// let mut x = 0u32;
+/// let ptr: Result<NonNullMut<u32>, _> = (&mut x as *mut u32).try_into();
+/// assert!(ptr.is_ok());
+///
+/// let ptr: Result<NonNullMut<u32>, _> = std::ptr::null_mut::<u32>().try_into();
+/// assert_eq!(ptr, Err(NullPtrError));
+/// ```
+impl<T: ?Sized> TryFrom<*mut T> for NonNullMut<T> {
+    type Error = NullPtrError;
+
+    #[inline]
+    fn try_from(ptr: *mut T) -> Result<Self, Self::Error> {
+        Self::new(ptr).ok_or(NullPtrError)
+    }
+}
+
 impl<T> NonNullMut<T> {
     /// Like [`NonNull::dangling`]
     ///
@@ -47,6 +226,79 @@ impl<T> NonNullMut<T> {
             _phantom: PhantomData,
         }
     }
+
+    /// Returns `true` if the address is equal to [`NonNullMut::dangling`]
+    ///
+    /// Note that a valid pointer may also have this address,
+    /// e.g a pointer to a zero-sized type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// assert!(NonNullMut::<u32>::dangling().is_dangling());
+    ///
+    /// let mut b = Box::new(2u32);
+    /// assert!(!NonNullMut::from(&mut *b).is_dangling());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_dangling(self) -> bool {
+        self.addr() == Self::dangling().addr()
+    }
+
+    /// Like [`NonNull::with_exposed_provenance`]
+    ///
+    /// The pointer picks up a previously exposed provenance,
+    /// see [`core::ptr::with_exposed_provenance_mut`] for details.
+    /// If no provenance was exposed for `addr`,
+    /// dereferencing the pointer is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut x = 2u32;
+    /// let addr = NonNullMut::from(&mut x).expose_provenance();
+    ///
+    /// let ptr = NonNullMut::<u32>::with_exposed_provenance(addr);
+    /// unsafe { ptr.write(ptr.read() + 1) };
+    /// assert_eq!(x, 3);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_exposed_provenance(addr: NonZeroUsize) -> Self {
+        Self::from_inner(NonNull::with_exposed_provenance(addr))
+    }
+
+    /// Create a pointer at the given address without any provenance,
+    /// like [`core::ptr::without_provenance_mut`]
+    ///
+    /// The pointer is only valid to dereference if the address genuinely
+    /// maps to memory outside the Rust abstract machine,
+    /// e.g a memory-mapped hardware register.
+    /// Otherwise, dereferencing it is undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::num::NonZeroUsize;
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// const ADDR: NonZeroUsize = NonZeroUsize::new(0x1000).unwrap();
+    /// const REG: NonNullMut<u32> = NonNullMut::from_addr(ADDR);
+    ///
+    /// assert_eq!(REG.addr(), ADDR);
+    /// assert_eq!(REG.addr().get(), 0x1000);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_addr(addr: NonZeroUsize) -> Self {
+        let ptr = core::ptr::without_provenance_mut(addr.get());
+        unsafe { Self::new_unchecked(ptr) }
+    }
 }
 
 impl<T: ?Sized> NonNullMut<T> {
@@ -80,7 +332,22 @@ impl<T: ?Sized> NonNullMut<T> {
     /// # Safety
     ///
     /// See [`NonNull::new_unchecked`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// The result must be used, it does not modify anything
+    ///
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut x = 0u32;
+    /// unsafe {
+    ///     NonNullMut::new_unchecked(&mut x as *mut u32);
+    /// }
+    /// ```
     #[inline]
+    #[must_use]
     pub const unsafe fn new_unchecked(ptr: *mut T) -> Self {
         let inner = unsafe { NonNull::new_unchecked(ptr) };
         Self {
@@ -90,6 +357,7 @@ impl<T: ?Sized> NonNullMut<T> {
     }
 
     /// Create [`NonNullMut<T>`] from [`NonNull<T>`]
+    #[must_use]
     pub const fn from_inner(inner: NonNull<T>) -> Self {
         Self {
             inner,
@@ -97,7 +365,82 @@ impl<T: ?Sized> NonNullMut<T> {
         }
     }
 
+    /// Like [`NonNull::from_raw_parts`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [1u8, 2, 3];
+    /// let ptr = NonNullMut::<[u8]>::from(&mut a[..]);
+    ///
+    /// let (data, len) = ptr.to_raw_parts();
+    /// assert_eq!(len, 3);
+    /// assert_eq!(NonNullMut::<[u8]>::from_raw_parts(data, len), ptr);
+    /// ```
+    #[cfg(feature = "ptr-metadata")]
+    #[inline]
+    #[must_use]
+    pub const fn from_raw_parts(data: NonNullMut<()>, metadata: <T as Pointee>::Metadata) -> Self {
+        Self::from_inner(NonNull::from_raw_parts(data.inner, metadata))
+    }
+
+    /// Like [`NonNull::to_raw_parts`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(ptr_metadata)]
+    /// use nonnull_mut::NonNullMut;
+    /// use std::fmt::Debug;
+    ///
+    /// let mut x = 2u32;
+    /// let ptr: NonNullMut<dyn Debug> = NonNullMut::from(&mut x as &mut dyn Debug);
+    ///
+    /// let (data, metadata) = ptr.to_raw_parts();
+    /// assert_eq!(data.addr(), ptr.addr());
+    /// assert_eq!(metadata.size_of(), 4);
+    ///
+    /// let ptr = NonNullMut::<dyn Debug>::from_raw_parts(data, metadata);
+    /// assert_eq!(format!("{:?}", unsafe { ptr.as_ref() }), "2");
+    /// ```
+    #[cfg(feature = "ptr-metadata")]
+    #[inline]
+    #[must_use]
+    pub const fn to_raw_parts(self) -> (NonNullMut<()>, <T as Pointee>::Metadata) {
+        let (data, metadata) = self.inner.to_raw_parts();
+        (NonNullMut::from_inner(data), metadata)
+    }
+
+    /// Like [`core::ptr::metadata`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(ptr_metadata)]
+    /// use nonnull_mut::NonNullMut;
+    /// use std::fmt::Debug;
+    ///
+    /// let mut a = [0u8; 6];
+    /// let ptr = NonNullMut::<[u8]>::from(&mut a[..]);
+    /// assert_eq!(ptr.metadata(), 6);
+    ///
+    /// let mut x = 2u16;
+    /// let ptr: NonNullMut<dyn Debug> = NonNullMut::from(&mut x as &mut dyn Debug);
+    /// assert_eq!(ptr.metadata().size_of(), 2);
+    /// assert_eq!(ptr.metadata().align_of(), 2);
+    /// ```
+    #[cfg(feature = "ptr-metadata")]
+    #[inline]
+    #[must_use]
+    pub const fn metadata(self) -> <T as Pointee>::Metadata {
+        core::ptr::metadata(self.as_ptr())
+    }
+
     /// Like [`NonNull::addr`]
+    ///
+    /// This cannot be `const`, because pointer addresses are not observable during const evaluation
     #[inline]
     #[must_use]
     pub fn addr(self) -> NonZeroUsize {
@@ -105,6 +448,8 @@ impl<T: ?Sized> NonNullMut<T> {
     }
 
     /// Like [`NonNull::with_addr`]
+    ///
+    /// This cannot be `const`, because pointer addresses are not observable during const evaluation
     #[inline]
     #[must_use]
     pub fn with_addr(self, addr: NonZeroUsize) -> Self {
@@ -112,374 +457,3457 @@ impl<T: ?Sized> NonNullMut<T> {
     }
 
     /// Like [`NonNull::map_addr`]
+    ///
+    /// This cannot be `const`, because pointer addresses are not observable during const evaluation
     #[inline]
     #[must_use]
     pub fn map_addr(self, f: impl FnOnce(NonZeroUsize) -> NonZeroUsize) -> Self {
         self.inner.map_addr(f).into()
     }
 
-    /// Like [`NonNull::as_ptr`]
-    #[inline(always)]
+    /// Display the address as lowercase hex with a `0x` prefix,
+    /// regardless of the pointer width and formatter flags
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::num::NonZeroUsize;
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let ptr = NonNullMut::<u8>::from_addr(NonZeroUsize::new(0xbeef0).unwrap());
+    ///
+    /// assert_eq!(ptr.addr_hex().to_string(), "0xbeef0");
+    /// assert_eq!(format!("addr: {}", ptr.addr_hex()), "addr: 0xbeef0");
+    /// ```
+    #[inline]
     #[must_use]
-    pub const fn as_ptr(self) -> *mut T {
-        self.inner.as_ptr()
+    pub fn addr_hex(self) -> AddrHex {
+        AddrHex(self.addr())
     }
 
-    /// Get inner [`NonNull<T>`]
-    pub const fn as_inner(self) -> NonNull<T> {
-        self.inner
+    /// Like [`NonNull::expose_provenance`]
+    ///
+    /// The provenance of the pointer is exposed, so that a later
+    /// [`NonNullMut::with_exposed_provenance`] with the same address can pick it up.
+    #[inline]
+    #[must_use]
+    pub fn expose_provenance(self) -> NonZeroUsize {
+        self.inner.expose_provenance()
     }
 
-    /// Like [`NonNull::as_ref`]
+    /// Compare both the address and the metadata (e.g slice length or vtable),
+    /// like [`core::ptr::eq`]
     ///
-    /// # Safety
+    /// This is equivalent to `==` on [`NonNullMut<T>`], which also compares the metadata,
+    /// use [`NonNullMut::addr_eq`] to ignore the metadata
     ///
-    /// See [`NonNull::as_ref`] for safety concerns and examples.
-    #[inline(always)]
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [0u8; 4];
+    /// let ptr = NonNullMut::<[u8]>::from(&mut a[..]);
+    /// let (head, _) = unsafe { ptr.split_at_mut_unchecked(2) };
+    ///
+    /// assert_eq!(head.addr(), ptr.addr());
+    /// assert!(!head.eq_with_metadata(ptr));
+    /// assert!(ptr.eq_with_metadata(ptr));
+    /// assert_eq!(head.eq_with_metadata(ptr), head == ptr);
+    /// ```
+    #[inline]
     #[must_use]
-    pub const unsafe fn as_ref<'a>(&self) -> &'a T {
-        unsafe { self.inner.as_ref() }
+    pub fn eq_with_metadata(self, other: Self) -> bool {
+        core::ptr::eq(self.as_ptr(), other.as_ptr())
     }
 
-    /// Like [`NonNull::as_mut`]
+    /// Compare only the addresses, ignoring the metadata,
+    /// like [`core::ptr::addr_eq`]
     ///
-    /// # Safety
+    /// # Examples
     ///
-    /// See [`NonNull::as_mut`] for safety concerns and examples.
-    #[inline(always)]
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [0u8; 4];
+    /// let ptr = NonNullMut::<[u8]>::from(&mut a[..]);
+    /// let (head, tail) = unsafe { ptr.split_at_mut_unchecked(2) };
+    ///
+    /// assert_ne!(head, ptr);
+    /// assert!(head.addr_eq(ptr));
+    /// assert!(!tail.addr_eq(ptr));
+    /// ```
+    #[inline]
     #[must_use]
-    pub const unsafe fn as_mut<'a>(&mut self) -> &'a mut T {
-        unsafe { self.inner.as_mut() }
+    pub fn addr_eq(self, other: Self) -> bool {
+        core::ptr::addr_eq(self.as_ptr(), other.as_ptr())
     }
 
-    /// Like [`NonNull::cast`]
+    /// Like [`NonNullMut::addr_eq`], but compare with a [`NonNull<T>`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    /// use std::ptr::NonNull;
+    ///
+    /// let mut a = [0u8; 4];
+    /// let ptr = NonNullMut::<[u8]>::from(&mut a[..]);
+    /// let head = NonNull::from(&mut a[..2]);
+    ///
+    /// assert_ne!(ptr, head);
+    /// assert!(ptr.addr_eq_raw(head));
+    /// assert!(!ptr.addr_eq_raw(NonNull::from(&mut a[1..])));
+    /// ```
     #[inline]
-    #[must_use = "this returns the result of the operation, \
-                  without modifying the original"]
-    pub const fn cast<U>(self) -> NonNull<U> {
-        self.inner.cast()
+    #[must_use]
+    pub fn addr_eq_raw(self, other: NonNull<T>) -> bool {
+        core::ptr::addr_eq(self.as_ptr(), other.as_ptr())
     }
 
-    /// Like [`NonNull::offset`]
+    /// Compare only the addresses, ignoring the metadata
     ///
-    /// # Safety
+    /// This is a total order independent of the metadata,
+    /// unlike comparing wide pointers directly
     ///
-    /// See [`NonNull::offset`] for safety concerns and examples.
-    #[inline(always)]
-    #[must_use = "returns a new pointer rather than modifying its argument"]
-    pub const unsafe fn offset(self, count: isize) -> Self
-    where
-        T: Sized,
-    {
-        unsafe { Self::from_inner(self.inner.offset(count)) }
-    }
-
-    /// Like [`NonNull::byte_offset`]
+    /// # Examples
     ///
-    /// # Safety
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    /// use std::cmp::Ordering;
     ///
-    /// See [`NonNull::byte_offset`] for safety concerns and examples.
-    #[inline(always)]
+    /// let mut a = [0u8; 8];
+    /// let ptr = NonNullMut::<[u8]>::from(&mut a[..]);
+    /// let (head, tail) = unsafe { ptr.split_at_mut_unchecked(3) };
+    /// let (mid, _) = unsafe { tail.split_at_mut_unchecked(1) };
+    ///
+    /// assert_eq!(head.cmp_addr(ptr), Ordering::Equal);
+    /// assert_eq!(mid.cmp_addr(head), Ordering::Greater);
+    ///
+    /// let mut ptrs = vec![tail, mid, ptr, head];
+    /// ptrs.sort_by(|a, b| a.cmp_addr(*b));
+    /// let addrs: Vec<usize> = ptrs.iter().map(|p| p.addr().get() - ptr.addr().get()).collect();
+    /// assert_eq!(addrs, [0, 0, 3, 3]);
+    /// assert_eq!(ptrs[0], ptr);
+    /// assert_eq!(ptrs[2], tail);
+    /// ```
+    #[inline]
     #[must_use]
-    pub const unsafe fn byte_offset(self, count: isize) -> Self {
-        unsafe { Self::from_inner(self.inner.byte_offset(count)) }
+    pub fn cmp_addr(self, other: Self) -> Ordering {
+        self.addr().cmp(&other.addr())
     }
 
-    /// Like [`NonNull::add`]
+    /// Returns the pointer with the lower address, `self` if the addresses are equal
     ///
-    /// # Safety
+    /// Only the addresses are compared, the metadata is ignored
     ///
-    /// See [`NonNull::add`] for safety concerns and examples.
-    #[inline(always)]
-    #[must_use = "returns a new pointer rather than modifying its argument"]
-    pub const unsafe fn add(self, count: usize) -> Self
-    where
-        T: Sized,
-    {
-        unsafe { Self::from_inner(self.inner.add(count)) }
-    }
-
-    /// Like [`NonNull::byte_add`]
+    /// # Examples
     ///
-    /// # Safety
+    /// ```
+    /// use nonnull_mut::NonNullMut;
     ///
-    /// See [`NonNull::byte_add`] for safety concerns and examples.
-    #[inline(always)]
+    /// let mut a = [0u32; 3];
+    /// let p0 = NonNullMut::new(a.as_mut_ptr()).unwrap();
+    /// let (p1, p2) = unsafe { (p0.add(1), p0.add(2)) };
+    ///
+    /// assert_eq!(p1.min_by_addr(p0), p0);
+    /// assert_eq!(p1.max_by_addr(p2), p2);
+    /// assert_eq!(p0.clamp_addr(p1, p2), p1);
+    /// assert_eq!(p2.clamp_addr(p0, p1).addr(), p1.addr());
+    /// assert_eq!(p1.clamp_addr(p0, p2), p1);
+    /// ```
+    #[inline]
     #[must_use]
-    pub const unsafe fn byte_add(self, count: usize) -> Self {
-        unsafe { Self::from_inner(self.inner.byte_add(count)) }
+    pub fn min_by_addr(self, other: Self) -> Self {
+        if other.addr() < self.addr() {
+            other
+        } else {
+            self
+        }
     }
 
-    /// Like [`NonNull::sub`]
+    /// Returns the pointer with the higher address, `self` if the addresses are equal
     ///
-    /// # Safety
-    ///
-    /// See [`NonNull::sub`] for safety concerns and examples.
-    #[inline(always)]
-    #[must_use = "returns a new pointer rather than modifying its argument"]
-    pub const unsafe fn sub(self, count: usize) -> Self
-    where
-        T: Sized,
-    {
-        unsafe { Self::from_inner(self.inner.sub(count)) }
+    /// Only the addresses are compared, the metadata is ignored
+    #[inline]
+    #[must_use]
+    pub fn max_by_addr(self, other: Self) -> Self {
+        if other.addr() > self.addr() {
+            other
+        } else {
+            self
+        }
     }
 
-    /// Like [`NonNull::byte_sub`]
+    /// Clamp the address between the addresses of `min` and `max`,
+    /// the provenance and metadata of `self` are kept, like [`NonNullMut::map_addr`]
     ///
-    /// # Safety
+    /// # Panics
     ///
-    /// See [`NonNull::byte_sub`] for safety concerns and examples.
-    #[inline(always)]
+    /// Panics if the address of `min` is greater than the address of `max`
+    #[inline]
     #[must_use]
-    pub const unsafe fn byte_sub(self, count: usize) -> Self {
-        unsafe { Self::from_inner(self.inner.byte_sub(count)) }
+    pub fn clamp_addr(self, min: Self, max: Self) -> Self {
+        self.map_addr(|addr| addr.clamp(min.addr(), max.addr()))
     }
 
-    /// Like [`NonNull::offset_from`]
+    /// Returns `true` if the address of `other` is within `alloc_len` elements
+    /// starting at `self`, i.e `self.addr() <= other.addr() < self.add(alloc_len).addr()`.
+    /// Always `false` for zero-sized `T`.
+    ///
+    /// This is a heuristic by address only, e.g for debugging double-frees.
+    /// It does not guarantee that both pointers have the same provenance,
+    /// nor that `self` actually points to an allocation of `alloc_len` elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [0u32; 8];
+    /// let base = NonNullMut::new(a.as_mut_ptr()).unwrap();
+    ///
+    /// unsafe {
+    ///     assert!(base.probably_same_alloc(base, 4));
+    ///     assert!(base.probably_same_alloc(base.add(3), 4));
+    ///     assert!(!base.probably_same_alloc(base.add(4), 4));
+    ///     assert!(!base.add(1).probably_same_alloc(base, 4));
+    ///     assert!(!base.probably_same_alloc(base, 0));
+    /// }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn probably_same_alloc(self, other: NonNullMut<T>, alloc_len: usize) -> bool
+    where
+        T: Sized,
+    {
+        let start = self.addr().get();
+        let size = alloc_len.saturating_mul(size_of::<T>());
+        let end = start.saturating_add(size);
+        (start..end).contains(&other.addr().get())
+    }
+
+    /// Map the raw pointer by a closure, returns [`None`] if the result is null
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [1u32, 2];
+    /// let ptr = NonNullMut::new(a.as_mut_ptr()).unwrap();
+    ///
+    /// let mapped = ptr.map_ptr(|p| p.wrapping_add(1)).unwrap();
+    /// assert_eq!(unsafe { mapped.read() }, 2);
+    ///
+    /// assert_eq!(ptr.map_ptr(|p| p), Some(ptr));
+    /// assert_eq!(ptr.map_ptr(|_| std::ptr::null_mut()), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn map_ptr(self, f: impl FnOnce(*mut T) -> *mut T) -> Option<Self> {
+        Self::new(f(self.as_ptr()))
+    }
+
+    /// Like [`NonNull::as_ptr`]
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_ptr(self) -> *mut T {
+        self.inner.as_ptr()
+    }
+
+    /// Get inner [`NonNull<T>`]
+    #[must_use]
+    pub const fn as_inner(self) -> NonNull<T> {
+        self.inner
+    }
+
+    /// Convert into a covariant [`NonNull<T>`], like [`NonNullMut::as_inner`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    /// use std::ptr::NonNull;
+    ///
+    /// const PTR: NonNullMut<u32> = NonNullMut::from_non_null(NonNull::dangling());
+    /// const RAW: NonNull<u32> = PTR.to_non_null();
+    ///
+    /// assert_eq!(RAW, NonNull::dangling());
+    /// assert_eq!(NonNullMut::from_non_null(RAW), PTR);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn to_non_null(self) -> NonNull<T> {
+        self.inner
+    }
+
+    /// Downgrade into a read-only [`NonNullConst<T>`],
+    /// documenting that the pointer will not be written through
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// fn sum(ptr: *const u32, len: usize) -> u32 {
+    ///     unsafe { std::slice::from_raw_parts(ptr, len) }.iter().sum()
+    /// }
+    ///
+    /// let mut a = [1u32, 2, 3];
+    /// let ptr = NonNullMut::new(a.as_mut_ptr()).unwrap();
+    /// let ptr = ptr.as_const();
+    ///
+    /// assert_eq!(sum(ptr.as_ptr(), 3), 6);
+    /// assert_eq!(sum(ptr.into(), 2), 3);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_const(self) -> NonNullConst<T> {
+        NonNullConst::from_inner(self.inner)
+    }
+
+    /// Convert from a covariant [`NonNull<T>`], like [`NonNullMut::from_inner`]
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_non_null(ptr: NonNull<T>) -> Self {
+        Self::from_inner(ptr)
+    }
+
+    /// Create from a shared reference, like `From<&T>` but `const`
+    ///
+    /// The pointer is derived from a shared reference,
+    /// it must not be written through (except inside `UnsafeCell`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// static X: u32 = 2;
+    /// const PTR: NonNullMut<u32> = NonNullMut::from_ref(&X);
+    ///
+    /// let val = const { unsafe { NonNullMut::from_ref(&3u32).read() } };
+    ///
+    /// assert_eq!(unsafe { PTR.read() }, 2);
+    /// assert_eq!(val, 3);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_ref(r: &T) -> Self {
+        Self::from_inner(NonNull::from_ref(r))
+    }
+
+    /// Create from an exclusive reference, like `From<&mut T>` but `const`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let val = const {
+    ///     let mut x = 1u32;
+    ///     let ptr = NonNullMut::from_mut(&mut x);
+    ///     unsafe { ptr.write(ptr.read() + 2) };
+    ///     x
+    /// };
+    ///
+    /// assert_eq!(val, 3);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_mut(r: &mut T) -> Self {
+        Self::from_inner(NonNull::from_mut(r))
+    }
+
+    /// Like [`NonNullMut::from_mut`], but also returns a reference reborrowed from the pointer
+    ///
+    /// The reference keeps the lifetime of `r`, and is derived from the pointer,
+    /// so the pointer stays usable after the reference is no longer used.
+    /// Accessing through the pointer while the reference is still used
+    /// is undefined behavior
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut x = 1u32;
+    /// let (ptr, r) = NonNullMut::from_mut_keep(&mut x);
+    ///
+    /// *r += 1;
+    /// assert_eq!(*r, 2);
+    ///
+    /// unsafe { ptr.write(ptr.read() + 1) };
+    /// assert_eq!(x, 3);
+    /// ```
+    ///
+    /// The original place is still borrowed while the reference is alive
+    ///
+    /// ```compile_fail,E0503
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut x = 1u32;
+    /// let (_ptr, r) = NonNullMut::from_mut_keep(&mut x);
+    /// let y = x;
+    /// *r += 1;
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_mut_keep(r: &mut T) -> (Self, &mut T) {
+        let ptr = Self::from_mut(r);
+        (ptr, unsafe { &mut *ptr.as_ptr() })
+    }
+
+    /// Like [`NonNull::as_ref`]
     ///
     /// # Safety
     ///
-    /// See [`NonNull::offset_from`] for safety concerns and examples.
+    /// See [`NonNull::as_ref`] for safety concerns and examples.
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn as_ref<'a>(&self) -> &'a T {
+        unsafe { self.inner.as_ref() }
+    }
+
+    /// Like [`NonNull::as_mut`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::as_mut`] for safety concerns and examples.
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn as_mut<'a>(&mut self) -> &'a mut T {
+        unsafe { self.inner.as_mut() }
+    }
+
+    /// Like [`NonNullMut::as_ref`], but returns `None` if the pointer
+    /// [`is_dangling`](NonNullMut::is_dangling)
+    ///
+    /// Useful when [`NonNullMut::dangling`] is used as a "not yet allocated" sentinel.
+    /// Note that pointers to zero-sized types are usually dangling
+    ///
+    /// # Safety
+    ///
+    /// If the pointer is not dangling,
+    /// see [`NonNull::as_ref`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let ptr = NonNullMut::<u32>::dangling();
+    /// assert_eq!(unsafe { ptr.as_ref_checked() }, None);
+    ///
+    /// let mut b = Box::new(2u32);
+    /// let ptr = NonNullMut::from(&mut *b);
+    /// assert_eq!(unsafe { ptr.as_ref_checked() }, Some(&2));
+    /// ```
     #[inline]
-    pub const unsafe fn offset_from(self, origin: NonNull<T>) -> isize
+    #[must_use]
+    pub unsafe fn as_ref_checked<'a>(self) -> Option<&'a T>
     where
         T: Sized,
     {
-        unsafe { self.inner.offset_from(origin) }
+        if self.is_dangling() {
+            None
+        } else {
+            Some(unsafe { self.inner.as_ref() })
+        }
     }
 
-    /// Like [`NonNull::byte_offset_from`]
+    /// Copy the pointer through an exclusive borrow
+    ///
+    /// The result is not lifetime bound to `self`,
+    /// the `&mut self` receiver only documents the exclusive access intent
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut x = 0u32;
+    /// let mut ptr = NonNullMut::from(&mut x);
+    ///
+    /// unsafe {
+    ///     ptr.reborrow().write(1);
+    ///     ptr.reborrow().write(ptr.reborrow().read() + 1);
+    /// }
+    /// assert_eq!(x, 2);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub fn reborrow(&mut self) -> Self {
+        *self
+    }
+
+    /// Like [`NonNullMut::as_ref`], but the lifetime of the reference is bound to `self`
     ///
     /// # Safety
     ///
-    /// See [`NonNull::byte_offset_from`] for safety concerns and examples.
+    /// See [`NonNull::as_ref`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut x = 2u32;
+    /// let ptr = NonNullMut::from(&mut x);
+    /// assert_eq!(unsafe { ptr.as_ref_bound() }, &2);
+    /// ```
+    ///
+    /// The pointer cannot be changed while the reference is alive
+    ///
+    /// ```compile_fail,E0506
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut x = 2u32;
+    /// let mut ptr = NonNullMut::from(&mut x);
+    /// let r = unsafe { ptr.as_ref_bound() };
+    /// ptr = NonNullMut::dangling();
+    /// assert_eq!(r, &2);
+    /// ```
     #[inline(always)]
-    pub const unsafe fn byte_offset_from<U: ?Sized>(self, origin: NonNull<U>) -> isize {
-        unsafe { self.inner.byte_offset_from(origin) }
+    #[must_use]
+    pub const unsafe fn as_ref_bound(&self) -> &T {
+        unsafe { self.inner.as_ref() }
     }
 
-    /// Like [`NonNull::read`]
+    /// Like [`NonNullMut::as_mut`], but the lifetime of the reference is bound to `self`
     ///
     /// # Safety
     ///
-    /// See [`NonNull::read`] for safety concerns and examples.
+    /// See [`NonNull::as_mut`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut x = 2u32;
+    /// let mut ptr = NonNullMut::from(&mut x);
+    /// *unsafe { ptr.as_mut_bound() } += 1;
+    /// assert_eq!(x, 3);
+    /// ```
+    ///
+    /// The pointer cannot be used while the reference is alive
+    ///
+    /// ```compile_fail,E0503
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut x = 2u32;
+    /// let mut ptr = NonNullMut::from(&mut x);
+    /// let r = unsafe { ptr.as_mut_bound() };
+    /// let _ = unsafe { ptr.read() };
+    /// *r += 1;
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn as_mut_bound(&mut self) -> &mut T {
+        unsafe { self.inner.as_mut() }
+    }
+
+    /// Create a pinned unique reference, like [`NonNull::as_mut`] then [`Pin::new_unchecked`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::as_mut`] for safety concerns and examples.
+    ///
+    /// In addition, the caller must uphold the pinning invariants of [`Pin::new_unchecked`]:
+    /// the pointee must never be moved or have its memory invalidated or reused
+    /// until it is dropped, even after the returned reference is gone,
+    /// unless `T: Unpin`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    /// use std::{
+    ///     future::Future,
+    ///     task::{Context, Poll, Waker},
+    /// };
+    ///
+    /// let mut fut = async { 42 };
+    /// let ptr = NonNullMut::from(&mut fut);
+    /// let mut cx = Context::from_waker(Waker::noop());
+    ///
+    /// let pinned = unsafe { ptr.as_pin_mut() };
+    /// assert_eq!(pinned.poll(&mut cx), Poll::Ready(42));
+    /// ```
     #[inline]
-    pub const unsafe fn read(self) -> T
+    #[must_use]
+    pub const unsafe fn as_pin_mut<'a>(self) -> Pin<&'a mut T>
     where
         T: Sized,
     {
-        unsafe { self.inner.read() }
+        unsafe { Pin::new_unchecked(&mut *self.as_ptr()) }
     }
 
-    /// Like [`NonNull::read_volatile`]
+    /// Like [`NonNull::as_uninit_ref`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::as_uninit_ref`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut x = MaybeUninit::<u32>::uninit();
+    /// let ptr = NonNullMut::new(x.as_mut_ptr()).unwrap();
+    ///
+    /// unsafe { ptr.as_uninit_mut() }.write(2);
+    /// assert_eq!(unsafe { ptr.as_uninit_ref().assume_init() }, 2);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn as_uninit_ref<'a>(self) -> &'a MaybeUninit<T>
+    where
+        T: Sized,
+    {
+        unsafe { self.inner.cast().as_ref() }
+    }
+
+    /// Like [`NonNull::as_uninit_mut`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::as_uninit_mut`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut x = MaybeUninit::<u32>::uninit();
+    /// let ptr = NonNullMut::new(x.as_mut_ptr()).unwrap();
+    ///
+    /// let r = unsafe { ptr.as_uninit_mut() };
+    /// r.write(3);
+    /// assert_eq!(unsafe { r.assume_init_read() }, 3);
+    /// assert_eq!(unsafe { x.assume_init() }, 3);
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn as_uninit_mut<'a>(self) -> &'a mut MaybeUninit<T>
+    where
+        T: Sized,
+    {
+        unsafe { self.inner.cast().as_mut() }
+    }
+
+    /// Like [`NonNull::cast`]
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const fn cast<U>(self) -> NonNull<U> {
+        self.inner.cast()
+    }
+
+    /// Cast to a pointer of `U`, then add `count` `U`-sized elements,
+    /// like `NonNullMut::from(self.cast::<U>()).add(count)`
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::add`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// A header followed by a typed payload
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// #[repr(C)]
+    /// struct Header {
+    ///     len: u16,
+    ///     tag: u16,
+    /// }
+    ///
+    /// #[repr(C, align(4))]
+    /// struct Buf([u8; 16]);
+    ///
+    /// let mut buf = Buf([0; 16]);
+    /// let header: NonNullMut<Header> = NonNullMut::from(&mut buf).cast().into();
+    ///
+    /// unsafe {
+    ///     header.write(Header { len: 3, tag: 7 });
+    ///     for i in 0..3 {
+    ///         header.add(1).cast_then_add::<u32>(i).write(i as u32 + 10);
+    ///     }
+    ///
+    ///     let header = header.read();
+    ///     assert_eq!((header.len, header.tag), (3, 7));
+    ///     let payload = NonNullMut::<[u8; 16]>::from(&mut buf.0).cast_then_add::<u32>(1);
+    ///     assert_eq!(payload.cast_slice(3).as_slice_ref(), [10, 11, 12]);
+    /// }
+    /// ```
+    #[inline]
+    #[must_use = "this returns the result of the operation, \
+                  without modifying the original"]
+    pub const unsafe fn cast_then_add<U>(self, count: usize) -> NonNullMut<U> {
+        NonNullMut::from_inner(unsafe { self.inner.cast::<U>().add(count) })
+    }
+
+    /// Like [`NonNullMut::cast`], but returns [`None`] if the pointer is not aligned for `U`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// #[repr(align(4))]
+    /// struct Aligned([u8; 8]);
+    ///
+    /// let mut buf = Aligned([0; 8]);
+    /// let ptr = NonNullMut::new(buf.0.as_mut_ptr()).unwrap();
+    ///
+    /// assert!(ptr.try_cast_aligned::<u32>().is_some());
+    /// assert!(unsafe { ptr.add(1) }.try_cast_aligned::<u32>().is_none());
+    /// assert!(unsafe { ptr.add(1) }.try_cast_aligned::<u8>().is_some());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn try_cast_aligned<U>(self) -> Option<NonNullMut<U>> {
+        if self.is_aligned_to(align_of::<U>()) {
+            Some(NonNullMut::from_inner(self.cast()))
+        } else {
+            None
+        }
+    }
+
+    /// Create a slice pointer of `len` elements starting at `self`,
+    /// like [`NonNullMut::slice_from_raw_parts`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [0u16; 5];
+    /// let ptr = NonNullMut::new(a.as_mut_ptr()).unwrap();
+    /// let slice = ptr.cast_slice(5);
+    ///
+    /// assert_eq!(slice.len(), 5);
+    /// assert_eq!(slice.as_non_null_ptr(), ptr);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn cast_slice(self, len: usize) -> NonNullMut<[T]>
+    where
+        T: Sized,
+    {
+        NonNullMut::slice_from_raw_parts(self, len)
+    }
+
+    /// Cast to an array pointer of `N` elements starting at `self`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [1u32, 2, 3, 4, 5];
+    /// let ptr = NonNullMut::new(a.as_mut_ptr()).unwrap();
+    ///
+    /// let array = unsafe { ptr.add(1) }.cast_array::<4>();
+    /// assert_eq!(unsafe { array.read() }, [2, 3, 4, 5]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn cast_array<const N: usize>(self) -> NonNullMut<[T; N]>
+    where
+        T: Sized,
+    {
+        NonNullMut::from_inner(self.inner.cast())
+    }
+
+    /// Like [`NonNull::offset`]
+    ///
+    /// In debug builds, panics if the offset in bytes does not fit in `isize`,
+    /// or if the resulting address is null.
+    /// Other address wrap-arounds are not detected, because this is a `const fn`
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::offset`] for safety concerns and examples.
+    #[inline(always)]
+    #[track_caller]
+    #[must_use = "returns a new pointer rather than modifying its argument"]
+    pub const unsafe fn offset(self, count: isize) -> Self
+    where
+        T: Sized,
+    {
+        debug_assert!(
+            count.checked_mul(size_of::<T>() as isize).is_some(),
+            "offset: byte offset overflows isize"
+        );
+        debug_assert!(
+            !self.inner.as_ptr().wrapping_offset(count).is_null(),
+            "offset: resulting address is null"
+        );
+        unsafe { Self::from_inner(self.inner.offset(count)) }
+    }
+
+    /// Like [`NonNull::byte_offset`]
+    ///
+    /// In debug builds, panics if the resulting address is null.
+    /// Other address wrap-arounds are not detected, because this is a `const fn`
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::byte_offset`] for safety concerns and examples.
+    #[inline(always)]
+    #[track_caller]
+    #[must_use]
+    pub const unsafe fn byte_offset(self, count: isize) -> Self {
+        debug_assert!(
+            !self.inner.as_ptr().wrapping_byte_offset(count).is_null(),
+            "byte_offset: resulting address is null"
+        );
+        unsafe { Self::from_inner(self.inner.byte_offset(count)) }
+    }
+
+    /// Like [`NonNull::add`]
+    ///
+    /// In debug builds, panics if the offset in bytes does not fit in `isize`,
+    /// or if the resulting address is null.
+    /// Other address wrap-arounds are not detected, because this is a `const fn`
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::add`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [1u32, 2, 3];
+    /// let ptr = NonNullMut::new(a.as_mut_ptr()).unwrap();
+    /// assert_eq!(unsafe { ptr.add(2).read() }, 3);
+    /// ```
+    ///
+    /// In debug builds, an offset of more than `isize::MAX` bytes panics
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    /// use std::panic;
+    ///
+    /// let mut a = [1u32, 2, 3];
+    /// let ptr = NonNullMut::new(a.as_mut_ptr()).unwrap();
+    ///
+    /// if cfg!(debug_assertions) {
+    ///     let result = panic::catch_unwind(|| unsafe { ptr.add(usize::MAX / 2) });
+    ///     assert!(result.is_err());
+    /// }
+    /// ```
+    #[inline(always)]
+    #[track_caller]
+    #[must_use = "returns a new pointer rather than modifying its argument"]
+    pub const unsafe fn add(self, count: usize) -> Self
+    where
+        T: Sized,
+    {
+        debug_assert!(
+            matches!(count.checked_mul(size_of::<T>()), Some(n) if n <= isize::MAX as usize),
+            "add: byte offset overflows isize"
+        );
+        debug_assert!(
+            !self.inner.as_ptr().wrapping_add(count).is_null(),
+            "add: resulting address is null"
+        );
+        unsafe { Self::from_inner(self.inner.add(count)) }
+    }
+
+    /// Like [`NonNull::byte_add`]
+    ///
+    /// In debug builds, panics if the offset in bytes does not fit in `isize`,
+    /// or if the resulting address is null.
+    /// Other address wrap-arounds are not detected, because this is a `const fn`
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::byte_add`] for safety concerns and examples.
+    #[inline(always)]
+    #[track_caller]
+    #[must_use]
+    pub const unsafe fn byte_add(self, count: usize) -> Self {
+        debug_assert!(
+            count <= isize::MAX as usize,
+            "byte_add: byte offset overflows isize"
+        );
+        debug_assert!(
+            !self.inner.as_ptr().wrapping_byte_add(count).is_null(),
+            "byte_add: resulting address is null"
+        );
+        unsafe { Self::from_inner(self.inner.byte_add(count)) }
+    }
+
+    /// Like [`NonNullMut::byte_add`], but returns [`None`] if the result
+    /// would be past `region_end`, or the address would overflow
+    ///
+    /// The result may equal `region_end`, i.e one past the end of the region.
+    /// This is address arithmetic only, the provenance of `self` is kept.
+    ///
+    /// Only the upper bound is checked,
+    /// `self` is not verified to be at or after the start of the region.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    /// use std::ptr::NonNull;
+    ///
+    /// let mut buf = [0u8; 16];
+    /// let start = NonNullMut::new(buf.as_mut_ptr()).unwrap();
+    /// let end: NonNull<u8> = unsafe { start.add(buf.len()) }.into();
+    ///
+    /// let ptr = start.checked_byte_add(8, end).unwrap();
+    /// assert_eq!(ptr.addr().get(), start.addr().get() + 8);
+    /// assert_eq!(ptr.checked_byte_add(8, end), Some(end.into()));
+    /// assert_eq!(ptr.checked_byte_add(9, end), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_byte_add(self, count: usize, region_end: NonNull<u8>) -> Option<Self> {
+        let addr = self.addr().checked_add(count)?;
+        (addr <= region_end.addr()).then(|| self.with_addr(addr))
+    }
+
+    /// Like [`NonNullMut::byte_sub`], but returns [`None`] if the result
+    /// would be before `region_start`
+    ///
+    /// This is address arithmetic only, the provenance of `self` is kept.
+    ///
+    /// Only the lower bound is checked,
+    /// `self` is not verified to be at or before the end of the region.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    /// use std::ptr::NonNull;
+    ///
+    /// let mut buf = [0u8; 16];
+    /// let start: NonNull<u8> = NonNull::new(buf.as_mut_ptr()).unwrap();
+    /// let end = unsafe { NonNullMut::from(start).add(buf.len()) };
+    ///
+    /// let ptr = end.checked_byte_sub(8, start).unwrap();
+    /// assert_eq!(ptr.addr().get(), start.addr().get() + 8);
+    /// assert_eq!(ptr.checked_byte_sub(8, start), Some(start.into()));
+    /// assert_eq!(ptr.checked_byte_sub(9, start), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_byte_sub(self, count: usize, region_start: NonNull<u8>) -> Option<Self> {
+        let addr = self.addr().get().checked_sub(count)?;
+        if addr < region_start.addr().get() {
+            return None;
+        }
+        NonZeroUsize::new(addr).map(|addr| self.with_addr(addr))
+    }
+
+    /// Like [`NonNull::sub`]
+    ///
+    /// In debug builds, panics if the offset in bytes does not fit in `isize`,
+    /// or if the resulting address is null.
+    /// Other address wrap-arounds are not detected, because this is a `const fn`
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::sub`] for safety concerns and examples.
+    #[inline(always)]
+    #[track_caller]
+    #[must_use = "returns a new pointer rather than modifying its argument"]
+    pub const unsafe fn sub(self, count: usize) -> Self
+    where
+        T: Sized,
+    {
+        debug_assert!(
+            matches!(count.checked_mul(size_of::<T>()), Some(n) if n <= isize::MAX as usize),
+            "sub: byte offset overflows isize"
+        );
+        debug_assert!(
+            !self.inner.as_ptr().wrapping_sub(count).is_null(),
+            "sub: resulting address is null"
+        );
+        unsafe { Self::from_inner(self.inner.sub(count)) }
+    }
+
+    /// Like [`NonNull::byte_sub`]
+    ///
+    /// In debug builds, panics if the offset in bytes does not fit in `isize`,
+    /// or if the resulting address is null.
+    /// Other address wrap-arounds are not detected, because this is a `const fn`
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::byte_sub`] for safety concerns and examples.
+    #[inline(always)]
+    #[track_caller]
+    #[must_use]
+    pub const unsafe fn byte_sub(self, count: usize) -> Self {
+        debug_assert!(
+            count <= isize::MAX as usize,
+            "byte_sub: byte offset overflows isize"
+        );
+        debug_assert!(
+            !self.inner.as_ptr().wrapping_byte_sub(count).is_null(),
+            "byte_sub: resulting address is null"
+        );
+        unsafe { Self::from_inner(self.inner.byte_sub(count)) }
+    }
+
+    /// Like `<*mut T>::wrapping_offset`, the result is assumed non-null
+    ///
+    /// # Safety
+    ///
+    /// The resulting pointer must not be null.
+    ///
+    /// Unlike [`NonNullMut::offset`], the result is not required to be
+    /// in bounds of an allocated object, but it may not be dereferenced if it's not.
+    #[inline(always)]
+    #[must_use = "returns a new pointer rather than modifying its argument"]
+    pub const unsafe fn wrapping_offset(self, count: isize) -> Self
+    where
+        T: Sized,
+    {
+        unsafe { Self::new_unchecked(self.as_ptr().wrapping_offset(count)) }
+    }
+
+    /// Like `<*mut T>::wrapping_add`, the result is assumed non-null
+    ///
+    /// # Safety
+    ///
+    /// The resulting pointer must not be null.
+    ///
+    /// Unlike [`NonNullMut::add`], the result is not required to be
+    /// in bounds of an allocated object, but it may not be dereferenced if it's not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [1u32, 2, 3, 4];
+    /// let ptr = NonNullMut::new(a.as_mut_ptr()).unwrap();
+    ///
+    /// unsafe {
+    ///     let out_of_bounds = ptr.wrapping_add(10);
+    ///     let back = out_of_bounds.wrapping_sub(8);
+    ///     assert_eq!(back.read(), 3);
+    ///     assert_eq!(ptr.wrapping_offset(7).wrapping_offset(-4).read(), 4);
+    ///     assert_eq!(ptr.wrapping_byte_add(64).wrapping_byte_sub(60).read(), 2);
+    /// }
+    /// ```
+    #[inline(always)]
+    #[must_use = "returns a new pointer rather than modifying its argument"]
+    pub const unsafe fn wrapping_add(self, count: usize) -> Self
+    where
+        T: Sized,
+    {
+        unsafe { Self::new_unchecked(self.as_ptr().wrapping_add(count)) }
+    }
+
+    /// Like `<*mut T>::wrapping_sub`, the result is assumed non-null
+    ///
+    /// # Safety
+    ///
+    /// The resulting pointer must not be null.
+    ///
+    /// Unlike [`NonNullMut::sub`], the result is not required to be
+    /// in bounds of an allocated object, but it may not be dereferenced if it's not.
+    #[inline(always)]
+    #[must_use = "returns a new pointer rather than modifying its argument"]
+    pub const unsafe fn wrapping_sub(self, count: usize) -> Self
+    where
+        T: Sized,
+    {
+        unsafe { Self::new_unchecked(self.as_ptr().wrapping_sub(count)) }
+    }
+
+    /// Like `<*mut T>::wrapping_byte_add`, the result is assumed non-null
+    ///
+    /// # Safety
+    ///
+    /// The resulting pointer must not be null.
+    ///
+    /// Unlike [`NonNullMut::byte_add`], the result is not required to be
+    /// in bounds of an allocated object, but it may not be dereferenced if it's not.
+    #[inline(always)]
+    #[must_use = "returns a new pointer rather than modifying its argument"]
+    pub const unsafe fn wrapping_byte_add(self, count: usize) -> Self {
+        unsafe { Self::new_unchecked(self.as_ptr().wrapping_byte_add(count)) }
+    }
+
+    /// Like `<*mut T>::wrapping_byte_sub`, the result is assumed non-null
+    ///
+    /// # Safety
+    ///
+    /// The resulting pointer must not be null.
+    ///
+    /// Unlike [`NonNullMut::byte_sub`], the result is not required to be
+    /// in bounds of an allocated object, but it may not be dereferenced if it's not.
+    #[inline(always)]
+    #[must_use = "returns a new pointer rather than modifying its argument"]
+    pub const unsafe fn wrapping_byte_sub(self, count: usize) -> Self {
+        unsafe { Self::new_unchecked(self.as_ptr().wrapping_byte_sub(count)) }
+    }
+
+    /// Like [`NonNull::offset_from`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::offset_from`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [0u32; 5];
+    /// let start = NonNullMut::new(a.as_mut_ptr()).unwrap();
+    /// let end = unsafe { start.add(5) };
+    ///
+    /// unsafe {
+    ///     assert_eq!(end.offset_from(start), 5);
+    ///     assert_eq!(start.offset_from(end), -5);
+    /// }
+    /// ```
+    #[inline]
+    pub const unsafe fn offset_from(self, origin: NonNullMut<T>) -> isize
+    where
+        T: Sized,
+    {
+        unsafe { self.inner.offset_from(origin.inner) }
+    }
+
+    /// The distance in elements from `self` to `other`, i.e `other.offset_from(self)`
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::offset_from`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [0u32; 5];
+    /// let start = NonNullMut::new(a.as_mut_ptr()).unwrap();
+    /// let mid = unsafe { start.add(2) };
+    ///
+    /// unsafe {
+    ///     assert_eq!(start.offset_to(mid), 2);
+    ///     assert_eq!(mid.offset_to(start), -2);
+    ///     assert_eq!(mid.offset_to(mid), 0);
+    /// }
+    /// ```
+    #[inline]
+    pub const unsafe fn offset_to(self, other: NonNullMut<T>) -> isize
+    where
+        T: Sized,
+    {
+        unsafe { other.offset_from(self) }
+    }
+
+    /// Like [`NonNull::byte_offset_from`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::byte_offset_from`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [0u32; 5];
+    /// let start = NonNullMut::<[u32; 5]>::from(&mut a);
+    /// let end = NonNullMut::from_inner(start.cast::<u8>());
+    /// let end = unsafe { end.add(20) };
+    ///
+    /// unsafe {
+    ///     assert_eq!(end.byte_offset_from(start), 20);
+    ///     assert_eq!(start.byte_offset_from(end), -20);
+    /// }
+    /// ```
+    ///
+    /// Measure the distance between two fields
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// #[repr(C)]
+    /// struct Foo {
+    ///     a: u16,
+    ///     b: u32,
+    /// }
+    ///
+    /// let mut foo = Foo { a: 0, b: 0 };
+    /// let a = nonnull_mut::nonnull_mut!(foo.a);
+    /// let b = nonnull_mut::nonnull_mut!(foo.b);
+    /// let foo = NonNullMut::from(&mut foo);
+    ///
+    /// assert_eq!(unsafe { b.byte_offset_from(a) }, 4);
+    /// assert_eq!(unsafe { a.byte_offset_from(foo) }, 0);
+    /// ```
+    #[inline(always)]
+    pub const unsafe fn byte_offset_from<U: ?Sized>(self, origin: NonNullMut<U>) -> isize {
+        unsafe { self.inner.byte_offset_from(origin.inner) }
+    }
+
+    /// Like [`NonNull::offset_from_unsigned`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::offset_from_unsigned`] for safety concerns and examples.
+    ///
+    /// In particular, `self` must not be less than `origin`,
+    /// otherwise it is immediate undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [0u32; 5];
+    /// let start = NonNullMut::new(a.as_mut_ptr()).unwrap();
+    /// let end = unsafe { start.add(a.len()) };
+    ///
+    /// assert_eq!(unsafe { end.offset_from_unsigned(start) }, 5);
+    /// ```
+    #[inline]
+    pub const unsafe fn offset_from_unsigned(self, origin: NonNullMut<T>) -> usize
+    where
+        T: Sized,
+    {
+        unsafe { self.inner.offset_from_unsigned(origin.inner) }
+    }
+
+    /// Like [`NonNull::byte_offset_from_unsigned`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::byte_offset_from_unsigned`] for safety concerns and examples.
+    ///
+    /// In particular, `self` must not be less than `origin`,
+    /// otherwise it is immediate undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [0u32; 5];
+    /// let start = NonNullMut::new(a.as_mut_ptr()).unwrap();
+    /// let end = unsafe { start.add(a.len()) };
+    ///
+    /// assert_eq!(unsafe { end.byte_offset_from_unsigned(start) }, 20);
+    /// ```
+    #[inline(always)]
+    pub const unsafe fn byte_offset_from_unsigned<U: ?Sized>(self, origin: NonNullMut<U>) -> usize {
+        unsafe { self.inner.byte_offset_from_unsigned(origin.inner) }
+    }
+
+    /// Like [`NonNull::read`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::read`] for safety concerns and examples.
+    #[inline]
+    pub const unsafe fn read(self) -> T
+    where
+        T: Sized,
+    {
+        unsafe { self.inner.read() }
+    }
+
+    /// Like [`NonNull::read_volatile`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::read_volatile`] for safety concerns and examples.
+    #[inline]
+    pub unsafe fn read_volatile(self) -> T
+    where
+        T: Sized,
+    {
+        unsafe { self.inner.read_volatile() }
+    }
+
+    /// Like [`NonNull::read_unaligned`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::read_unaligned`] for safety concerns and examples.
+    #[inline]
+    pub const unsafe fn read_unaligned(self) -> T
+    where
+        T: Sized,
+    {
+        unsafe { self.inner.read_unaligned() }
+    }
+
+    /// Like [`NonNull::copy_to`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::copy_to`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [1u32, 2, 3, 4, 0];
+    /// let ptr = NonNullMut::new(a.as_mut_ptr()).unwrap();
+    ///
+    /// unsafe { ptr.copy_to(ptr.add(1), 4) };
+    /// assert_eq!(a, [1, 1, 2, 3, 4]);
+    /// ```
+    #[inline(always)]
+    pub const unsafe fn copy_to(self, dest: NonNullMut<T>, count: usize)
+    where
+        T: Sized,
+    {
+        unsafe { self.inner.copy_to(dest.inner, count) }
+    }
+
+    /// Like [`NonNull::copy_to_nonoverlapping`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::copy_to_nonoverlapping`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [1u32, 2, 3, 4];
+    /// let mut b = [0u32; 4];
+    /// let src = NonNullMut::new(a.as_mut_ptr()).unwrap();
+    /// let dest = NonNullMut::new(b.as_mut_ptr()).unwrap();
+    ///
+    /// unsafe { src.copy_to_nonoverlapping(dest, 4) };
+    /// assert_eq!(b, [1, 2, 3, 4]);
+    /// ```
+    #[inline(always)]
+    pub const unsafe fn copy_to_nonoverlapping(self, dest: NonNullMut<T>, count: usize)
+    where
+        T: Sized,
+    {
+        unsafe { self.inner.copy_to_nonoverlapping(dest.inner, count) }
+    }
+
+    /// Like [`NonNull::copy_from`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::copy_from`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [0u32, 1, 2, 3, 4];
+    /// let ptr = NonNullMut::new(a.as_mut_ptr()).unwrap();
+    ///
+    /// unsafe { ptr.copy_from(ptr.add(1), 4) };
+    /// assert_eq!(a, [1, 2, 3, 4, 4]);
+    /// ```
+    #[inline(always)]
+    pub const unsafe fn copy_from(self, src: NonNullMut<T>, count: usize)
+    where
+        T: Sized,
+    {
+        unsafe { self.inner.copy_from(src.inner, count) }
+    }
+
+    /// Like [`NonNull::copy_from_nonoverlapping`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::copy_from_nonoverlapping`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [1u32, 2, 3, 4];
+    /// let mut b = [0u32; 4];
+    /// let src = NonNullMut::new(a.as_mut_ptr()).unwrap();
+    /// let dest = NonNullMut::new(b.as_mut_ptr()).unwrap();
+    ///
+    /// unsafe { dest.copy_from_nonoverlapping(src, 4) };
+    /// assert_eq!(b, [1, 2, 3, 4]);
+    /// ```
+    #[inline(always)]
+    pub const unsafe fn copy_from_nonoverlapping(self, src: NonNullMut<T>, count: usize)
+    where
+        T: Sized,
+    {
+        unsafe { self.inner.copy_from_nonoverlapping(src.inner, count) }
+    }
+
+    /// Copies `count` elements from `self` to `dest` by volatile accesses
+    ///
+    /// Unlike [`NonNullMut::copy_to`], this is not a single `memcpy`,
+    /// each element is read by [`NonNullMut::read_volatile`]
+    /// and written by [`NonNullMut::write_volatile`] in ascending order.
+    ///
+    /// # Safety
+    ///
+    /// For each element, see [`NonNull::read_volatile`] and [`NonNull::write_volatile`]
+    /// for safety concerns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [1u32, 2, 3, 4];
+    /// let mut b = [0u32; 4];
+    /// let src = NonNullMut::new(a.as_mut_ptr()).unwrap();
+    /// let dest = NonNullMut::new(b.as_mut_ptr()).unwrap();
+    ///
+    /// unsafe { src.copy_to_volatile(dest, 4) };
+    /// assert_eq!(b, [1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    pub unsafe fn copy_to_volatile(self, dest: NonNullMut<T>, count: usize)
+    where
+        T: Sized,
+    {
+        for i in 0..count {
+            unsafe { dest.add(i).write_volatile(self.add(i).read_volatile()) }
+        }
+    }
+
+    /// Copies `count` elements from `src` to `self` by volatile accesses
+    ///
+    /// Unlike [`NonNullMut::copy_from`], this is not a single `memcpy`,
+    /// each element is read by [`NonNullMut::read_volatile`]
+    /// and written by [`NonNullMut::write_volatile`] in ascending order.
+    ///
+    /// # Safety
+    ///
+    /// For each element, see [`NonNull::read_volatile`] and [`NonNull::write_volatile`]
+    /// for safety concerns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [1u32, 2, 3, 4];
+    /// let mut b = [0u32; 4];
+    /// let src = NonNullMut::new(a.as_mut_ptr()).unwrap();
+    /// let dest = NonNullMut::new(b.as_mut_ptr()).unwrap();
+    ///
+    /// unsafe { dest.copy_from_volatile(src, 4) };
+    /// assert_eq!(b, [1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    pub unsafe fn copy_from_volatile(self, src: NonNullMut<T>, count: usize)
+    where
+        T: Sized,
+    {
+        unsafe { src.copy_to_volatile(self, count) }
+    }
+
+    /// Like [`NonNull::drop_in_place`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::drop_in_place`] for safety concerns and examples.
+    #[inline(always)]
+    pub unsafe fn drop_in_place(self) {
+        unsafe { self.inner.drop_in_place() }
+    }
+
+    /// Like [`NonNull::write`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::write`] for safety concerns and examples.
+    #[inline(always)]
+    pub const unsafe fn write(self, val: T)
+    where
+        T: Sized,
+    {
+        unsafe { self.inner.write(val) }
+    }
+
+    /// Like [`NonNull::write_bytes`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::write_bytes`] for safety concerns and examples.
+    #[inline(always)]
+    pub const unsafe fn write_bytes(self, val: u8, count: usize)
+    where
+        T: Sized,
+    {
+        unsafe { self.inner.write_bytes(val, count) }
+    }
+
+    /// Set all bytes of the pointee to zero, like `self.write_bytes(0, 1)`
+    ///
+    /// The old value is not dropped
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::write_bytes`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut x = u64::MAX;
+    /// unsafe { NonNullMut::from(&mut x).write_zeroed() };
+    /// assert_eq!(x, 0);
+    /// ```
+    #[inline(always)]
+    pub const unsafe fn write_zeroed(self)
+    where
+        T: Sized,
+    {
+        unsafe { self.write_bytes(0, 1) }
+    }
+
+    /// Set all bytes of the pointee to zero by volatile writes,
+    /// followed by a [`compiler_fence`],
+    /// so that the writes are not elided by the optimizer
+    ///
+    /// This is best-effort secure clearing, not a cryptographic guarantee,
+    /// e.g copies of the value may remain in registers or elsewhere in memory
+    ///
+    /// The old value is not dropped
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::write_volatile`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut key = [0xA5u8; 32];
+    /// unsafe { NonNullMut::<[u8; 32]>::from(&mut key).volatile_zeroize() };
+    /// assert_eq!(key, [0; 32]);
+    /// ```
+    #[inline]
+    pub unsafe fn volatile_zeroize(self)
+    where
+        T: Sized,
+    {
+        unsafe { volatile_zero_bytes(self.cast().into(), size_of::<T>()) }
+    }
+
+    /// Like [`NonNull::write_volatile`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::write_volatile`] for safety concerns and examples.
+    #[inline(always)]
+    pub unsafe fn write_volatile(self, val: T)
+    where
+        T: Sized,
+    {
+        unsafe { self.inner.write_volatile(val) }
+    }
+
+    /// Like [`NonNull::write_unaligned`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::write_unaligned`] for safety concerns and examples.
+    #[inline(always)]
+    pub const unsafe fn write_unaligned(self, val: T)
+    where
+        T: Sized,
+    {
+        unsafe { self.inner.write_unaligned(val) }
+    }
+
+    /// Like [`NonNull::replace`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::replace`] for safety concerns and examples.
+    #[inline(always)]
+    pub unsafe fn replace(self, src: T) -> T
+    where
+        T: Sized,
+    {
+        unsafe { self.inner.replace(src) }
+    }
+
+    /// Reads the current value, computes a new value from it by `f`, and writes it back
+    ///
+    /// The current value is moved into `f`, so non-`Copy` values can be transformed
+    ///
+    /// If `f` panics, the process is aborted,
+    /// because the value has already been moved out but the pointee still holds it,
+    /// unwinding could drop it twice
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::read`] and [`NonNull::write`] for safety concerns.
+    /// The pointee must not be accessed through other pointers during `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut count = 1u32;
+    /// let ptr = NonNullMut::from(&mut count);
+    ///
+    /// unsafe {
+    ///     ptr.replace_with(|n| n + 1);
+    ///     ptr.replace_with(|n| n + 1);
+    /// }
+    /// assert_eq!(count, 3);
+    ///
+    /// let mut s = String::from("foo");
+    /// let ptr = NonNullMut::from(&mut s);
+    ///
+    /// unsafe { ptr.replace_with(|s| s + "bar") };
+    /// assert_eq!(s, "foobar");
+    /// ```
+    #[inline]
+    pub unsafe fn replace_with(self, f: impl FnOnce(T) -> T)
+    where
+        T: Sized,
+    {
+        struct AbortOnUnwind;
+
+        impl Drop for AbortOnUnwind {
+            fn drop(&mut self) {
+                // panicking during unwinding aborts
+                panic!("replace_with: closure panicked");
+            }
+        }
+
+        let old = unsafe { self.read() };
+        let guard = AbortOnUnwind;
+        let new = f(old);
+        mem::forget(guard);
+        unsafe { self.write(new) }
+    }
+
+    /// Like [`NonNull::swap`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::swap`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = 1u32;
+    /// let mut b = 2u32;
+    /// let pa = NonNullMut::from(&mut a);
+    /// let pb = NonNullMut::from(&mut b);
+    ///
+    /// unsafe { pa.swap(pb) };
+    /// assert_eq!((a, b), (2, 1));
+    /// ```
+    #[inline(always)]
+    pub const unsafe fn swap(self, with: NonNullMut<T>)
+    where
+        T: Sized,
+    {
+        unsafe { self.inner.swap(with.inner) }
+    }
+
+    /// Like [`NonNull::align_offset`]
+    #[inline]
+    #[must_use]
+    pub fn align_offset(self, align: usize) -> usize
+    where
+        T: Sized,
+    {
+        self.inner.align_offset(align)
+    }
+
+    /// Like [`NonNull::is_aligned`]
+    #[inline]
+    #[must_use]
+    pub fn is_aligned(self) -> bool
+    where
+        T: Sized,
+    {
+        self.inner.is_aligned()
+    }
+
+    /// Like [`NonNull::is_aligned_to`]
+    ///
+    /// In debug builds, panics if `align` is not a power-of-two
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// #[repr(align(16))]
+    /// struct Aligned([u8; 32]);
+    ///
+    /// let mut buf = Aligned([0; 32]);
+    /// let ptr = NonNullMut::from(&mut buf);
+    ///
+    /// assert!(ptr.is_aligned_to(16));
+    /// assert!(ptr.is_aligned_to(4));
+    ///
+    /// let ptr = unsafe { ptr.byte_add(1) };
+    /// assert!(ptr.is_aligned_to(1));
+    /// assert!(!ptr.is_aligned_to(2));
+    /// assert!(!ptr.is_aligned_to(16));
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn is_aligned_to(self, align: usize) -> bool {
+        debug_assert!(
+            align.is_power_of_two(),
+            "is_aligned_to: align is not a power-of-two"
+        );
+        self.addr().get() & (align - 1) == 0
+    }
+
+    /// Round the address up to a multiple of `align`, keeping the provenance
+    ///
+    /// The result is never smaller than `self`
+    ///
+    /// In debug builds, panics if `align` is not a power-of-two
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rounded address overflows `usize`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::num::NonZeroUsize;
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let addr = |n| NonZeroUsize::new(n).unwrap();
+    /// let ptr = NonNullMut::<u8>::dangling().with_addr(addr(0x1234));
+    ///
+    /// assert_eq!(ptr.align_up(16).addr(), addr(0x1240));
+    /// assert_eq!(ptr.align_up(1), ptr);
+    ///
+    /// let ptr = ptr.with_addr(addr(0x1240));
+    /// assert_eq!(ptr.align_up(16), ptr);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn align_up(self, align: usize) -> Self {
+        debug_assert!(
+            align.is_power_of_two(),
+            "align_up: align is not a power-of-two"
+        );
+        let addr = self
+            .addr()
+            .checked_add(align - 1)
+            .map(|addr| addr.get() & !(align - 1))
+            .and_then(NonZeroUsize::new)
+            .expect("align_up: address overflow");
+        self.with_addr(addr)
+    }
+
+    /// Round the address down to a multiple of `align`, keeping the provenance
+    ///
+    /// The result is never greater than `self`
+    ///
+    /// In debug builds, panics if `align` is not a power-of-two
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rounded address is zero, i.e `self.addr() < align`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::num::NonZeroUsize;
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let addr = |n| NonZeroUsize::new(n).unwrap();
+    /// let ptr = NonNullMut::<u8>::dangling().with_addr(addr(0x1234));
+    ///
+    /// assert_eq!(ptr.align_down(16).addr(), addr(0x1230));
+    /// assert_eq!(ptr.align_down(1), ptr);
+    ///
+    /// let ptr = ptr.with_addr(addr(0x1230));
+    /// assert_eq!(ptr.align_down(16), ptr);
+    /// ```
+    ///
+    /// Rounding down to zero panics
+    ///
+    /// ```should_panic
+    /// use core::num::NonZeroUsize;
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let ptr = NonNullMut::<u8>::dangling().with_addr(NonZeroUsize::new(8).unwrap());
+    /// let _ = ptr.align_down(16);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn align_down(self, align: usize) -> Self {
+        debug_assert!(
+            align.is_power_of_two(),
+            "align_down: align is not a power-of-two"
+        );
+        let addr = NonZeroUsize::new(self.addr().get() & !(align - 1));
+        self.with_addr(addr.expect("align_down: rounded address is zero"))
+    }
+}
+
+impl<T> NonNullMut<[T]> {
+    /// Like [`NonNull::slice_from_raw_parts`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [1u8, 2, 3, 4];
+    /// let data = NonNullMut::new(a.as_mut_ptr()).unwrap();
+    /// let ptr = NonNullMut::slice_from_raw_parts(data, 3);
+    ///
+    /// assert_eq!(ptr.len(), 3);
+    /// assert_eq!(ptr.as_non_null_ptr(), data);
+    /// assert_eq!(unsafe { ptr.as_ref() }, &[1, 2, 3]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn slice_from_raw_parts(data: NonNullMut<T>, len: usize) -> Self {
+        Self::from_inner(NonNull::slice_from_raw_parts(data.inner, len))
+    }
+
+    /// Create a slice pointer of length `N` from an exclusive reference to an array
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [0u8; 8];
+    /// let ptr = NonNullMut::from_array_mut(&mut a);
+    /// assert_eq!(ptr.len(), 8);
+    /// assert_eq!(ptr.as_mut_ptr(), a.as_mut_ptr());
+    ///
+    /// let mut a = [0u8; 0];
+    /// let ptr = NonNullMut::from_array_mut(&mut a);
+    /// assert_eq!(ptr.len(), 0);
+    /// assert_eq!(ptr.as_mut_ptr(), a.as_mut_ptr());
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_array_mut<const N: usize>(r: &mut [T; N]) -> Self {
+        Self::from_mut(r)
+    }
+
+    /// Create a slice pointer of length `N` from a shared reference to an array
+    ///
+    /// The pointer is derived from a shared reference,
+    /// it must not be written through (except inside `UnsafeCell`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let a = [0u8; 8];
+    /// let ptr = NonNullMut::from_array_ref(&a);
+    /// assert_eq!(ptr.len(), 8);
+    /// assert_eq!(ptr.as_mut_ptr().cast_const(), a.as_ptr());
+    ///
+    /// let a = [0u8; 0];
+    /// let ptr = NonNullMut::from_array_ref(&a);
+    /// assert_eq!(ptr.len(), 0);
+    /// assert_eq!(ptr.as_mut_ptr().cast_const(), a.as_ptr());
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_array_ref<const N: usize>(r: &[T; N]) -> Self {
+        Self::from_ref(r)
+    }
+
+    /// Like [`NonNullMut::slice_from_raw_parts`], but returns [`None`] if `data` is null
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [1u8, 2, 3];
+    /// let ptr = NonNullMut::new_slice(a.as_mut_ptr(), 3).unwrap();
+    /// assert_eq!(ptr.len(), 3);
+    /// assert_eq!(ptr.as_mut_ptr(), a.as_mut_ptr());
+    ///
+    /// assert!(NonNullMut::<[u8]>::new_slice(std::ptr::null_mut(), 3).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn new_slice(data: *mut T, len: usize) -> Option<Self> {
+        match NonNullMut::new(data) {
+            Some(data) => Some(Self::slice_from_raw_parts(data, len)),
+            None => None,
+        }
+    }
+
+    /// Create an empty slice pointer, with a dangling but well-aligned data pointer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let ptr = NonNullMut::<[u64]>::empty_slice();
+    ///
+    /// assert_eq!(ptr.len(), 0);
+    /// assert!(ptr.is_empty());
+    /// assert_eq!(ptr.as_non_null_ptr(), NonNullMut::dangling());
+    /// assert!(ptr.as_non_null_ptr().is_aligned());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn empty_slice() -> Self {
+        Self::slice_from_raw_parts(NonNullMut::dangling(), 0)
+    }
+
+    /// Like [`NonNull::len`]
+    #[inline]
+    #[must_use]
+    pub const fn len(self) -> usize {
+        self.inner.len()
+    }
+
+    /// Like [`NonNull::is_empty`]
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Like [`slice::first_mut`], returns the first element pointer,
+    /// or [`None`] if it is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [1u32, 2, 3];
+    /// let ptr = NonNullMut::<[u32]>::from(&mut a[..]);
+    /// assert_eq!(ptr.first_ptr(), Some(ptr.as_non_null_ptr()));
+    ///
+    /// assert_eq!(NonNullMut::<[u32]>::empty_slice().first_ptr(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn first_ptr(self) -> Option<NonNullMut<T>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.as_non_null_ptr())
+        }
+    }
+
+    /// Like [`slice::last_mut`], returns the last element pointer,
+    /// or [`None`] if it is empty
+    ///
+    /// The pointer is computed by wrapping arithmetic, no memory is accessed
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pointer address overflows to null
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [1u32, 2, 3];
+    /// let ptr = NonNullMut::<[u32]>::from(&mut a[..]);
+    /// let last = ptr.last_ptr().unwrap();
+    /// assert_eq!(last.addr().get(), ptr.addr().get() + 8);
+    /// assert_eq!(unsafe { last.read() }, 3);
+    ///
+    /// let (one, _) = unsafe { ptr.split_at_mut_unchecked(1) };
+    /// assert_eq!(one.last_ptr(), one.first_ptr());
+    ///
+    /// assert_eq!(NonNullMut::<[u32]>::empty_slice().last_ptr(), None);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn last_ptr(self) -> Option<NonNullMut<T>> {
+        if self.is_empty() {
+            return None;
+        }
+        let data = self.as_non_null_ptr();
+        let last = data.map_ptr(|ptr| ptr.wrapping_add(self.len() - 1));
+        Some(last.expect("last_ptr: address overflow"))
+    }
+
+    /// Like [`slice::split_first_mut`], returns the first element pointer
+    /// and the rest subslice pointer, or [`None`] if it is empty
+    ///
+    /// # Safety
+    ///
+    /// The whole slice must be in bounds of a single allocated object,
+    /// see [`NonNull::add`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [1u32, 2, 3];
+    /// let ptr = NonNullMut::<[u32]>::from(&mut a[..]);
+    ///
+    /// unsafe {
+    ///     let (first, rest) = ptr.split_first_ptr().unwrap();
+    ///     assert_eq!(first.read(), 1);
+    ///     let (last, rest) = rest.split_last_ptr().unwrap();
+    ///     assert_eq!(last.read(), 3);
+    ///     let (first, rest) = rest.split_first_ptr().unwrap();
+    ///     assert_eq!(first.read(), 2);
+    ///
+    ///     assert!(rest.is_empty());
+    ///     assert!(rest.split_first_ptr().is_none());
+    ///     assert!(rest.split_last_ptr().is_none());
+    /// }
+    /// ```
+    #[inline]
+    pub unsafe fn split_first_ptr(self) -> Option<(NonNullMut<T>, Self)> {
+        if self.is_empty() {
+            return None;
+        }
+        let (first, rest) = unsafe { self.split_at_mut_unchecked(1) };
+        Some((first.as_non_null_ptr(), rest))
+    }
+
+    /// Like [`slice::split_last_mut`], returns the last element pointer
+    /// and the rest subslice pointer, or [`None`] if it is empty
+    ///
+    /// # Safety
+    ///
+    /// The whole slice must be in bounds of a single allocated object,
+    /// see [`NonNull::add`].
+    #[inline]
+    pub unsafe fn split_last_ptr(self) -> Option<(NonNullMut<T>, Self)> {
+        if self.is_empty() {
+            return None;
+        }
+        let (rest, last) = unsafe { self.split_at_mut_unchecked(self.len() - 1) };
+        Some((last.as_non_null_ptr(), rest))
+    }
+
+    /// Like [`NonNull::as_non_null_ptr`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [1u32, 2, 3];
+    /// let ptr = NonNullMut::<[u32]>::from(&mut a[..]);
+    ///
+    /// assert_eq!(ptr.as_non_null_ptr().addr(), ptr.addr());
+    /// assert_eq!(unsafe { ptr.as_non_null_ptr().read() }, 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_non_null_ptr(self) -> NonNullMut<T> {
+        NonNullMut::from_inner(self.inner.cast())
+    }
+
+    /// Like [`NonNull::as_mut_ptr`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [1u32, 2, 3];
+    /// let ptr = NonNullMut::<[u32]>::from(&mut a[..]);
+    ///
+    /// assert_eq!(ptr.as_mut_ptr(), a.as_mut_ptr());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_mut_ptr(self) -> *mut T {
+        self.as_non_null_ptr().as_ptr()
+    }
+
+    /// Like [`slice::split_at_mut_unchecked`], but no references are formed
+    ///
+    /// # Safety
+    ///
+    /// Caller has to check that `mid <= self.len()`,
+    /// and that the whole slice is in bounds of a single allocated object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [0u32; 10];
+    /// let ptr = NonNullMut::<[u32]>::from(&mut a[..]);
+    ///
+    /// let (left, right) = unsafe { ptr.split_at_mut_unchecked(4) };
+    /// assert_eq!(left.len(), 4);
+    /// assert_eq!(right.len(), 6);
+    /// assert_eq!(left.as_non_null_ptr(), ptr.as_non_null_ptr());
+    /// assert_eq!(right.as_non_null_ptr(), unsafe { ptr.as_non_null_ptr().add(4) });
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub const unsafe fn split_at_mut_unchecked(self, mid: usize) -> (Self, Self) {
+        debug_assert!(mid <= self.len(), "split_at_mut_unchecked: mid > len");
+        let len = self.len();
+        let data = self.as_non_null_ptr();
+        let tail = unsafe { data.add(mid) };
+        (
+            Self::slice_from_raw_parts(data, mid),
+            Self::slice_from_raw_parts(tail, len - mid),
+        )
+    }
+
+    /// Split at the first element whose address is aligned to `align`,
+    /// returns the unaligned prefix and the aligned remainder
+    ///
+    /// The split index is [`NonNullMut::align_offset`] of the base pointer,
+    /// clamped to `self.len()`, so the remainder is empty if
+    /// no element in the slice is aligned.
+    ///
+    /// The pointers are computed by wrapping arithmetic, no memory is accessed
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power-of-two
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// #[repr(align(16))]
+    /// struct Aligned([u8; 64]);
+    ///
+    /// let mut buf = Aligned([0; 64]);
+    /// let ptr = NonNullMut::<[u8]>::from(&mut buf.0[3..]);
+    ///
+    /// let (prefix, rest) = ptr.split_at_aligned(16);
+    /// assert_eq!(prefix.len(), 13);
+    /// assert_eq!(rest.len(), 64 - 16);
+    /// assert_eq!(prefix.as_non_null_ptr(), ptr.as_non_null_ptr());
+    /// assert!(rest.as_non_null_ptr().is_aligned_to(16));
+    ///
+    /// let short = NonNullMut::<[u8]>::from(&mut buf.0[3..10]);
+    /// let (prefix, rest) = short.split_at_aligned(16);
+    /// assert_eq!(prefix, short);
+    /// assert!(rest.is_empty());
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn split_at_aligned(self, align: usize) -> (Self, Self) {
+        let data = self.as_non_null_ptr();
+        let len = self.len();
+        let mid = data.align_offset(align).min(len);
+        let tail = data.map_ptr(|ptr| ptr.wrapping_add(mid));
+        let tail = tail.expect("split_at_aligned: address overflow");
+        (
+            Self::slice_from_raw_parts(data, mid),
+            Self::slice_from_raw_parts(tail, len - mid),
+        )
+    }
+
+    /// Like [`slice::as_mut_ptr_range`], the end pointer is one past the last element
+    ///
+    /// The end pointer is computed by wrapping arithmetic, no memory is accessed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [0u32; 4];
+    /// let ptr = NonNullMut::<[u32]>::from(&mut a[..]);
+    /// let range = ptr.as_ptr_range();
+    ///
+    /// assert_eq!(range.start, ptr.as_mut_ptr());
+    /// assert!(range.contains(&ptr.as_mut_ptr().wrapping_add(2)));
+    /// assert!(!range.contains(&ptr.as_mut_ptr().wrapping_add(4)));
+    /// assert!(!range.contains(&ptr.as_mut_ptr().wrapping_sub(1)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_ptr_range(self) -> Range<*mut T> {
+        let start = self.as_mut_ptr();
+        start..start.wrapping_add(self.len())
+    }
+
+    /// Returns `true` if `ptr` points to an element of the slice
+    ///
+    /// That is, `ptr` is in [`NonNullMut::as_ptr_range`] and the offset from the base
+    /// is a multiple of `size_of::<T>()`. Always `false` for zero-sized `T`.
+    ///
+    /// This is address arithmetic only, the provenance of `ptr` is not validated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [0u32; 4];
+    /// let ptr = NonNullMut::<[u32]>::from(&mut a[..]);
+    /// let base = ptr.as_non_null_ptr();
+    ///
+    /// unsafe {
+    ///     assert!(ptr.contains_ptr(base));
+    ///     assert!(ptr.contains_ptr(base.add(3)));
+    ///     assert!(!ptr.contains_ptr(base.add(4)));
+    ///     assert!(!ptr.contains_ptr(base.byte_add(2)));
+    ///     assert!(!ptr.contains_ptr(base.wrapping_sub(1)));
+    /// }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn contains_ptr(self, ptr: NonNullMut<T>) -> bool {
+        let range = self.as_ptr_range();
+        let offset = ptr.addr().get().wrapping_sub(range.start.addr());
+        range.contains(&ptr.as_ptr()) && offset.is_multiple_of(size_of::<T>())
+    }
+
+    /// Returns the index of the element `elem` points to,
+    /// or `None` if it is not an element of the slice, see [`NonNullMut::contains_ptr`]
+    ///
+    /// The index is computed from the addresses,
+    /// like [`NonNullMut::offset_from_unsigned`] but safe to call
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [0u32; 5];
+    /// let ptr = NonNullMut::<[u32]>::from(&mut a[..]);
+    /// let base = ptr.as_non_null_ptr();
+    ///
+    /// unsafe {
+    ///     assert_eq!(ptr.index_of(base), Some(0));
+    ///     assert_eq!(ptr.index_of(base.add(2)), Some(2));
+    ///     assert_eq!(ptr.index_of(base.add(ptr.len() - 1)), Some(4));
+    ///     assert_eq!(ptr.index_of(base.add(5)), None);
+    ///     assert_eq!(ptr.index_of(base.byte_add(1)), None);
+    /// }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn index_of(self, elem: NonNullMut<T>) -> Option<usize> {
+        if !self.contains_ptr(elem) {
+            return None;
+        }
+        let offset = elem.addr().get() - self.addr().get();
+        Some(offset / size_of::<T>())
+    }
+
+    /// Like [`Cell::as_slice_of_cells`], reinterpret as a slice pointer of [`Cell<T>`]
+    ///
+    /// This is sound because [`Cell<T>`] has the same memory layout as `T`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [1u32, 2, 3];
+    /// let ptr = NonNullMut::<[u32]>::from(&mut a[..]);
+    /// let cells = ptr.as_cell_slice();
+    ///
+    /// assert_eq!(cells.len(), 3);
+    /// assert_eq!(cells.addr(), ptr.addr());
+    ///
+    /// let cells = unsafe { cells.as_ref() };
+    /// cells[0].set(cells[2].get());
+    /// assert_eq!(a, [3, 2, 3]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_cell_slice(self) -> NonNullMut<[Cell<T>]> {
+        let data = NonNullMut::from_inner(self.inner.cast());
+        NonNullMut::slice_from_raw_parts(data, self.len())
+    }
+
+    /// Like [`NonNull::get_unchecked_mut`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::get_unchecked_mut`] for safety concerns and examples.
+    ///
+    /// Calling this method with an out-of-bounds index is undefined behavior,
+    /// even if the resulting pointer is not used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [1u32, 2, 3, 4];
+    /// let ptr = NonNullMut::<[u32]>::from(&mut a[..]);
+    ///
+    /// unsafe {
+    ///     let elem = ptr.get_unchecked_mut(2);
+    ///     assert_eq!(elem.read(), 3);
+    ///
+    ///     let sub = ptr.get_unchecked_mut(1..3);
+    ///     assert_eq!(sub.len(), 2);
+    ///     assert_eq!(sub.as_ref(), &[2, 3]);
+    /// }
+    /// ```
+    #[cfg(feature = "slice-ptr-get")]
+    #[inline]
+    pub unsafe fn get_unchecked_mut<I>(self, index: I) -> NonNullMut<I::Output>
+    where
+        I: SliceIndex<[T]>,
+    {
+        unsafe { NonNullMut::from_inner(self.inner.get_unchecked_mut(index)) }
+    }
+
+    /// Like [`NonNull::as_uninit_slice`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::as_uninit_slice`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf = [MaybeUninit::<u32>::uninit(); 3];
+    /// let data = NonNullMut::new(buf.as_mut_ptr().cast::<u32>()).unwrap();
+    /// let ptr = NonNullMut::slice_from_raw_parts(data, buf.len());
+    ///
+    /// for (i, elem) in unsafe { ptr.as_uninit_slice_mut() }.iter_mut().enumerate() {
+    ///     elem.write(i as u32 * 10);
+    /// }
+    ///
+    /// let slice = unsafe { ptr.as_uninit_slice() };
+    /// assert_eq!(slice.len(), 3);
+    /// assert_eq!(unsafe { slice[2].assume_init() }, 20);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const unsafe fn as_uninit_slice<'a>(self) -> &'a [MaybeUninit<T>] {
+        unsafe { slice::from_raw_parts(self.inner.cast().as_ptr(), self.len()) }
+    }
+
+    /// Like [`NonNull::as_uninit_slice_mut`]
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::as_uninit_slice_mut`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf = [MaybeUninit::<u32>::uninit(); 4];
+    /// let data = NonNullMut::new(buf.as_mut_ptr().cast::<u32>()).unwrap();
+    /// let ptr = NonNullMut::slice_from_raw_parts(data, buf.len());
+    ///
+    /// let slice = unsafe { ptr.as_uninit_slice_mut() };
+    /// for (i, elem) in slice.iter_mut().enumerate() {
+    ///     elem.write(i as u32 + 1);
+    /// }
+    ///
+    /// let values = buf.map(|elem| unsafe { elem.assume_init() });
+    /// assert_eq!(values, [1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const unsafe fn as_uninit_slice_mut<'a>(self) -> &'a mut [MaybeUninit<T>] {
+        unsafe { slice::from_raw_parts_mut(self.inner.cast().as_ptr(), self.len()) }
+    }
+
+    /// Create a shared slice reference with the stored length
+    ///
+    /// # Safety
+    ///
+    /// See [`slice::from_raw_parts`] and [`NonNull::as_ref`] for safety concerns.
+    ///
+    /// The lifetime `'a` is arbitrarily chosen, the caller must ensure
+    /// that the memory is not mutated (except inside `UnsafeCell`)
+    /// while the reference is alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [1u32, 2, 3, 4];
+    /// let ptr = NonNullMut::<[u32]>::from(&mut a[1..]);
+    ///
+    /// assert_eq!(unsafe { ptr.as_slice_ref() }, &[2, 3, 4]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const unsafe fn as_slice_ref<'a>(self) -> &'a [T] {
+        unsafe { slice::from_raw_parts(self.inner.cast().as_ptr(), self.len()) }
+    }
+
+    /// Create a unique slice reference with the stored length
+    ///
+    /// # Safety
+    ///
+    /// See [`slice::from_raw_parts_mut`] and [`NonNull::as_mut`] for safety concerns.
+    ///
+    /// The lifetime `'a` is arbitrarily chosen, the caller must ensure
+    /// that the memory is not accessed through any other pointer
+    /// while the reference is alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [1u32, 2, 3, 4];
+    /// let ptr = NonNullMut::<[u32]>::from(&mut a[..]);
+    ///
+    /// let slice = unsafe { ptr.as_slice_mut() };
+    /// slice.iter_mut().for_each(|x| *x *= 10);
+    /// slice.swap(0, 3);
+    ///
+    /// assert_eq!(unsafe { ptr.as_slice_ref() }, &[40, 20, 30, 10]);
+    /// assert_eq!(a, [40, 20, 30, 10]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const unsafe fn as_slice_mut<'a>(self) -> &'a mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.inner.cast().as_ptr(), self.len()) }
+    }
+
+    /// Cast the element type to `U`, keeping the address and the element count
+    ///
+    /// This preserves the number of elements, not the number of bytes,
+    /// the byte size differs unless `size_of::<T>() == size_of::<U>()`.
+    /// See [`NonNullMut::cast_slice_elems`] for the byte-preserving cast
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [-1i32, 2, -3];
+    /// let ptr = NonNullMut::<[i32]>::from(&mut a[..]);
+    /// let unsigned = ptr.cast_elems_keep_len::<u32>();
+    ///
+    /// assert_eq!(unsigned.len(), 3);
+    /// assert_eq!(unsigned.addr(), ptr.addr());
+    /// assert_eq!(unsafe { unsigned.as_slice_ref() }, [u32::MAX, 2, 3u32.wrapping_neg()]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn cast_elems_keep_len<U>(self) -> NonNullMut<[U]> {
+        let data = NonNullMut::from_inner(self.as_non_null_ptr().inner.cast::<U>());
+        NonNullMut::slice_from_raw_parts(data, self.len())
+    }
+
+    /// Reinterpret the slice pointer as a slice of `U`, keeping the address
+    /// and recomputing the length from the byte size
+    ///
+    /// The new length is `len * size_of::<T>() / size_of::<U>()`
+    ///
+    /// In debug builds, panics if the byte size is not a multiple of `size_of::<U>()`,
+    /// or if the address is not aligned to `align_of::<U>()`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `U` is zero-sized
+    ///
+    /// # Safety
+    ///
+    /// The byte size must be a multiple of `size_of::<U>()`,
+    /// and the address must be aligned to `align_of::<U>()`.
+    ///
+    /// Reading through the result is only valid if the bytes are valid values of `U`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [1u32, 2, 3, 4];
+    /// let ptr = NonNullMut::<[u32]>::from(&mut a[..]);
+    /// let bytes = unsafe { ptr.cast_slice_elems::<u8>() };
+    ///
+    /// assert_eq!(bytes.len(), 16);
+    /// assert_eq!(bytes.addr(), ptr.addr());
+    /// assert_eq!(unsafe { bytes.as_slice_ref() }[4..8], 2u32.to_ne_bytes());
+    ///
+    /// let words = unsafe { bytes.cast_slice_elems::<u32>() };
+    /// assert_eq!(words, ptr);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub unsafe fn cast_slice_elems<U>(self) -> NonNullMut<[U]> {
+        assert!(size_of::<U>() != 0, "cast_slice_elems: U is zero-sized");
+        let size = self.len() * size_of::<T>();
+        debug_assert!(
+            size.is_multiple_of(size_of::<U>()),
+            "cast_slice_elems: byte size is not a multiple of the size of U"
+        );
+        debug_assert!(
+            self.addr().get().is_multiple_of(align_of::<U>()),
+            "cast_slice_elems: address is not aligned for U"
+        );
+        let data = NonNullMut::from_inner(self.as_non_null_ptr().cast::<U>());
+        NonNullMut::slice_from_raw_parts(data, size / size_of::<U>())
+    }
+
+    /// Returns an iterator over the pointers of each element
+    ///
+    /// The pointers are computed by wrapping arithmetic, no memory is accessed
+    ///
+    /// # Panics
+    ///
+    /// Panics if an element pointer address overflows to null
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [1u32, 2, 3, 4];
+    /// let ptr = NonNullMut::<[u32]>::from(&mut a[..]);
+    ///
+    /// let iter = ptr.iter_ptrs();
+    /// assert_eq!(iter.len(), 4);
+    ///
+    /// let values: Vec<u32> = iter.map(|p| unsafe { p.read() }).collect();
+    /// assert_eq!(values, [1, 2, 3, 4]);
+    ///
+    /// let values: Vec<u32> = ptr.iter_ptrs().rev().map(|p| unsafe { p.read() }).collect();
+    /// assert_eq!(values, [4, 3, 2, 1]);
+    ///
+    /// let addrs: Vec<usize> = ptr.iter_ptrs().map(|p| p.addr().get()).collect();
+    /// for pair in addrs.windows(2) {
+    ///     assert_eq!(pair[1] - pair[0], size_of::<u32>());
+    /// }
+    /// ```
+    #[inline]
+    pub fn iter_ptrs(self) -> ElemPtrs<T> {
+        ElemPtrs::new(self)
+    }
+
+    /// Returns an iterator over `chunk_len` elements subslice pointers,
+    /// like [`slice::chunks`], the last chunk may be shorter
+    ///
+    /// The pointers are computed by wrapping arithmetic, no memory is accessed
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_len` is zero,
+    /// or if a chunk pointer address overflows to null
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [0u8; 10];
+    /// let ptr = NonNullMut::<[u8]>::from(&mut a[..]);
+    ///
+    /// let lens: Vec<usize> = ptr.chunks_ptr(3).map(|chunk| chunk.len()).collect();
+    /// assert_eq!(lens, [3, 3, 3, 1]);
+    ///
+    /// let lens: Vec<usize> = ptr.chunks_ptr(3).rev().map(|chunk| chunk.len()).collect();
+    /// assert_eq!(lens, [1, 3, 3, 3]);
+    ///
+    /// let last = ptr.chunks_ptr(3).last().unwrap();
+    /// assert_eq!(last.addr().get(), ptr.addr().get() + 9);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let _ = NonNullMut::<[u8]>::empty_slice().chunks_ptr(0);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn chunks_ptr(self, chunk_len: usize) -> ChunkPtrs<T> {
+        ChunkPtrs::new(self, chunk_len)
+    }
+
+    /// Split into a slice pointer of `N` elements arrays and the remainder,
+    /// like [`slice::as_chunks`]
+    ///
+    /// The pointers are computed by wrapping arithmetic, no memory is accessed
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero,
+    /// or if the remainder pointer address overflows to null
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [0u8; 10];
+    /// let ptr = NonNullMut::<[u8]>::from(&mut a[..]);
+    ///
+    /// let (chunks, rest) = ptr.as_chunks_ptr::<4>();
+    /// assert_eq!(chunks.len(), 2);
+    /// assert_eq!(rest.len(), 2);
+    /// assert_eq!(chunks.addr(), ptr.addr());
+    /// assert_eq!(rest.addr().get(), ptr.addr().get() + 8);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let _ = NonNullMut::<[u8]>::empty_slice().as_chunks_ptr::<0>();
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn as_chunks_ptr<const N: usize>(self) -> (NonNullMut<[[T; N]]>, Self) {
+        assert!(N != 0, "chunk size must be non-zero");
+        let data = self.as_non_null_ptr();
+        let count = self.len() / N;
+        let mid = count * N;
+        let tail = data.map_ptr(|ptr| ptr.wrapping_add(mid));
+        let tail = tail.expect("as_chunks_ptr: address overflow");
+        let chunks = NonNullMut::from_inner(data.cast::<[T; N]>());
+        (
+            NonNullMut::slice_from_raw_parts(chunks, count),
+            Self::slice_from_raw_parts(tail, self.len() - mid),
+        )
+    }
+
+    /// Reads the element at `index`, like [`NonNull::read`]
+    ///
+    /// In debug builds, panics if `index >= self.len()`
+    ///
+    /// # Safety
+    ///
+    /// `index` must be less than `self.len()`,
+    /// and see [`NonNull::add`] and [`NonNull::read`] for safety concerns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [0u32; 4];
+    /// let ptr = NonNullMut::<[u32]>::from(&mut a[..]);
+    ///
+    /// unsafe {
+    ///     ptr.write_at(0, 1);
+    ///     ptr.write_at(3, 4);
+    ///     assert_eq!(ptr.read_at(0), 1);
+    ///     assert_eq!(ptr.read_at(1), 0);
+    ///     assert_eq!(ptr.read_at(3), 4);
+    /// }
+    /// assert_eq!(a, [1, 0, 0, 4]);
+    /// ```
+    ///
+    /// In debug builds, the out of bounds panic reports the location of the caller
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    /// use std::{panic, sync::Mutex};
+    ///
+    /// static LINE: Mutex<Option<u32>> = Mutex::new(None);
+    ///
+    /// panic::set_hook(Box::new(|info| {
+    ///     *LINE.lock().unwrap() = info.location().map(|loc| loc.line());
+    /// }));
+    ///
+    /// let mut a = [0u32; 5];
+    /// let ptr = NonNullMut::<[u32]>::from(&mut a[..4]);
+    ///
+    /// let expected = line!() + 1;
+    /// let result = panic::catch_unwind(|| unsafe { ptr.read_at(4) });
+    /// let _ = panic::take_hook();
+    ///
+    /// // Only panics if the library is built with debug assertions
+    /// if result.is_err() {
+    ///     assert_eq!(*LINE.lock().unwrap(), Some(expected));
+    /// }
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub unsafe fn read_at(self, index: usize) -> T {
+        debug_assert!(index < self.len(), "read_at: index out of bounds");
+        unsafe { self.as_non_null_ptr().add(index).read() }
+    }
+
+    /// Overwrites the element at `index` without dropping the old value,
+    /// like [`NonNull::write`]
+    ///
+    /// In debug builds, panics if `index >= self.len()`
+    ///
+    /// # Safety
+    ///
+    /// `index` must be less than `self.len()`,
+    /// and see [`NonNull::add`] and [`NonNull::write`] for safety concerns.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn write_at(self, index: usize, val: T) {
+        debug_assert!(index < self.len(), "write_at: index out of bounds");
+        unsafe { self.as_non_null_ptr().add(index).write(val) }
+    }
+
+    /// Set all bytes of the elements to `val`, like [`NonNullMut::write_bytes`]
+    /// with `self.len()` elements
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::write_bytes`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [0u16; 3];
+    /// unsafe { NonNullMut::<[u16]>::from(&mut a[..]).write_bytes_slice(0xFF) };
+    /// assert_eq!(a, [0xFFFF; 3]);
+    /// ```
+    #[inline(always)]
+    pub const unsafe fn write_bytes_slice(self, val: u8) {
+        unsafe { self.as_non_null_ptr().write_bytes(val, self.len()) }
+    }
+
+    /// Like [`NonNullMut::volatile_zeroize`], set all bytes of the elements to zero
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::write_volatile`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [u32::MAX; 5];
+    /// let ptr = NonNullMut::<[u32]>::from(&mut a[1..4]);
+    ///
+    /// unsafe { ptr.volatile_zeroize_slice() };
+    /// assert_eq!(a, [u32::MAX, 0, 0, 0, u32::MAX]);
+    /// ```
+    #[inline]
+    pub unsafe fn volatile_zeroize_slice(self) {
+        let len = size_of::<T>() * self.len();
+        unsafe { volatile_zero_bytes(self.as_non_null_ptr().cast().into(), len) }
+    }
+
+    /// Copies all elements from `src` into `self`, like [`slice::copy_from_slice`]
+    ///
+    /// In debug builds, panics if the length of `src` is not equal to `self.len()`
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::copy_from_nonoverlapping`] for safety concerns,
+    /// the `count` is `self.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf = [MaybeUninit::<u32>::uninit(); 4];
+    /// let data = NonNullMut::new(buf.as_mut_ptr().cast::<u32>()).unwrap();
+    /// let ptr = NonNullMut::slice_from_raw_parts(data, buf.len());
+    ///
+    /// unsafe {
+    ///     ptr.write_slice(&[1, 2, 3, 4]);
+    ///     assert_eq!(ptr.as_ref(), &[1, 2, 3, 4]);
+    /// }
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub unsafe fn write_slice(self, src: &[T])
+    where
+        T: Copy,
+    {
+        debug_assert_eq!(src.len(), self.len(), "write_slice: length mismatch");
+        let src = NonNullMut::from(src).as_non_null_ptr();
+        unsafe {
+            self.as_non_null_ptr()
+                .copy_from_nonoverlapping(src, self.len())
+        }
+    }
+
+    /// Like [`NonNullMut::write_slice`], but the elements may be unaligned,
+    /// each element is written by [`NonNull::write_unaligned`]
+    ///
+    /// In debug builds, panics if the length of `src` is not equal to `self.len()`
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::write_unaligned`] for safety concerns and examples,
+    /// for each of the `self.len()` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut buf = [0u8; 13];
+    /// let data = NonNullMut::new(buf[1..].as_mut_ptr().cast::<u32>()).unwrap();
+    /// let ptr = NonNullMut::slice_from_raw_parts(data, 3);
+    ///
+    /// unsafe {
+    ///     ptr.write_unaligned_slice(&[1, 2, 0xAABBCCDD]);
+    ///     assert_eq!(data.read_unaligned(), 1);
+    ///     assert_eq!(data.add(1).read_unaligned(), 2);
+    ///     assert_eq!(data.add(2).read_unaligned(), 0xAABBCCDD);
+    /// }
+    /// assert_eq!(buf[0], 0);
+    /// assert_eq!(buf[1..5], 1u32.to_ne_bytes());
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub unsafe fn write_unaligned_slice(self, src: &[T])
+    where
+        T: Copy,
+    {
+        debug_assert_eq!(
+            src.len(),
+            self.len(),
+            "write_unaligned_slice: length mismatch"
+        );
+        let data = self.as_non_null_ptr();
+        for (i, &elem) in src.iter().enumerate().take(self.len()) {
+            unsafe { data.add(i).write_unaligned(elem) }
+        }
+    }
+
+    /// Writes `f(i)` into each element `i` in `0..self.len()`, like [`NonNull::write`]
+    ///
+    /// The previous contents are overwritten without being dropped,
+    /// so this can be used to initialize uninitialized memory.
+    /// If `f` panics, the already written elements are not dropped
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::add`] and [`NonNull::write`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf = [MaybeUninit::<usize>::uninit(); 5];
+    /// let data = NonNullMut::new(buf.as_mut_ptr().cast::<usize>()).unwrap();
+    /// let ptr = NonNullMut::slice_from_raw_parts(data, buf.len());
+    ///
+    /// unsafe {
+    ///     ptr.fill_with(|i| i * i);
+    ///     assert_eq!(ptr.as_slice_ref(), [0, 1, 4, 9, 16]);
+    /// }
+    /// ```
+    #[inline]
+    pub unsafe fn fill_with(self, mut f: impl FnMut(usize) -> T) {
+        let data = self.as_non_null_ptr();
+        for i in 0..self.len() {
+            unsafe { data.add(i).write(f(i)) }
+        }
+    }
+
+    /// Swaps the elements at `a` and `b`, like [`NonNull::swap`]
+    ///
+    /// `a` and `b` may be equal
+    ///
+    /// In debug builds, panics if `a` or `b` is out of bounds
+    ///
+    /// # Safety
+    ///
+    /// `a` and `b` must be less than `self.len()`,
+    /// and see [`NonNull::add`] and [`NonNull::swap`] for safety concerns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [1u32, 2, 3, 4];
+    /// let ptr = NonNullMut::<[u32]>::from(&mut a[..]);
+    ///
+    /// unsafe {
+    ///     ptr.swap_elements(0, 3);
+    ///     ptr.swap_elements(1, 2);
+    ///     ptr.swap_elements(2, 2);
+    /// }
+    /// assert_eq!(a, [4, 3, 2, 1]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub unsafe fn swap_elements(self, a: usize, b: usize) {
+        debug_assert!(a < self.len(), "swap_elements: index a out of bounds");
+        debug_assert!(b < self.len(), "swap_elements: index b out of bounds");
+        let data = self.as_non_null_ptr();
+        unsafe { data.add(a).swap(data.add(b)) }
+    }
+}
+
+#[cfg(feature = "ptr-metadata")]
+impl<Dyn: ?Sized> NonNullMut<Dyn>
+where
+    Dyn: Pointee<Metadata = DynMetadata<Dyn>>,
+{
+    /// Like [`NonNullMut::from_raw_parts`], but for trait objects
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(ptr_metadata)]
+    /// use nonnull_mut::NonNullMut;
+    /// use std::fmt::Debug;
+    ///
+    /// let mut x = 2u32;
+    /// let vtable = std::ptr::metadata(&x as &dyn Debug);
+    /// let data = NonNullMut::from_inner(NonNullMut::from(&mut x).cast::<()>());
+    ///
+    /// let ptr = NonNullMut::<dyn Debug>::from_thin_and_vtable(data, vtable);
+    /// assert_eq!(format!("{:?}", unsafe { ptr.as_ref() }), "2");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_thin_and_vtable(data: NonNullMut<()>, vtable: DynMetadata<Dyn>) -> Self {
+        Self::from_raw_parts(data, vtable)
+    }
+}
+
+impl NonNullMut<[u8]> {
+    /// Like [`slice::align_to`], split the bytes into an unaligned prefix,
+    /// a well-aligned middle of `U`, and a suffix
+    ///
+    /// This does not read memory, it only computes the pointers and lengths.
+    ///
+    /// If `U` is zero-sized, or the slice is too short to be aligned,
+    /// returns `self` as the prefix and empty slices for the others
+    ///
+    /// # Safety
+    ///
+    /// The whole slice must be in bounds of a single allocated object,
+    /// see [`NonNull::add`].
+    ///
+    /// Reinterpreting the bytes as `U` is only valid if they are valid values of `U`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// #[repr(align(4))]
+    /// struct Aligned([u8; 16]);
+    ///
+    /// let mut buf = Aligned([0; 16]);
+    /// let ptr = NonNullMut::<[u8]>::from(&mut buf.0[..]);
+    /// let (_, bytes) = unsafe { ptr.split_at_mut_unchecked(1) };
+    ///
+    /// let (prefix, middle, suffix) = unsafe { bytes.align_to::<u32>() };
+    /// assert_eq!(prefix.len(), 3);
+    /// assert_eq!(middle.len(), 3);
+    /// assert_eq!(suffix.len(), 0);
+    /// assert!(middle.as_non_null_ptr().is_aligned());
+    /// assert_eq!(middle.addr().get(), bytes.addr().get() + 3);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub unsafe fn align_to<U>(self) -> (Self, NonNullMut<[U]>, Self) {
+        let data = self.as_non_null_ptr();
+        let len = self.len();
+        let offset = data.align_offset(align_of::<U>());
+        if size_of::<U>() == 0 || offset > len {
+            return (self, NonNullMut::empty_slice(), Self::empty_slice());
+        }
+        let count = (len - offset) / size_of::<U>();
+        let middle_len = count * size_of::<U>();
+        let (prefix, rest) = unsafe { self.split_at_mut_unchecked(offset) };
+        let (middle, suffix) = unsafe { rest.split_at_mut_unchecked(middle_len) };
+        let middle = NonNullMut::from_inner(middle.cast::<U>());
+        (prefix, middle.cast_slice(count), suffix)
+    }
+}
+
+impl<T, const N: usize> NonNullMut<[T; N]> {
+    /// Returns the array length `N`, like [`NonNullMut::len`] for slice pointers
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut a = [0u8; 5];
+    /// let ptr = NonNullMut::from(&mut a);
+    /// assert_eq!(ptr.len_of_array(), 5);
+    /// assert!(!ptr.is_empty());
+    /// assert_eq!(NonNullMut::<[u8]>::from(ptr).len(), ptr.len_of_array());
+    ///
+    /// let ptr = NonNullMut::<[u8; 0]>::dangling();
+    /// assert_eq!(ptr.len_of_array(), 0);
+    /// assert!(ptr.is_empty());
+    /// ```
+    #[inline(always)]
+    #[must_use]
+    pub const fn len_of_array(self) -> usize {
+        N
+    }
+
+    /// Returns `true` if the array length `N` is zero,
+    /// like [`NonNullMut::is_empty`] for slice pointers
+    #[inline(always)]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        N == 0
+    }
+}
+
+impl<T> NonNullMut<Option<T>> {
+    /// Like [`Option::take`], replace the slot with [`None`] and returns the old value
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::replace`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let mut slot = Some(2u32);
+    /// let ptr = NonNullMut::from(&mut slot);
+    ///
+    /// unsafe {
+    ///     assert_eq!(ptr.take(), Some(2));
+    ///     assert_eq!(ptr.take(), None);
+    /// }
+    /// assert_eq!(slot, None);
+    /// ```
+    #[inline]
+    pub unsafe fn take(self) -> Option<T> {
+        unsafe { self.replace(None) }
+    }
+
+    /// Like [`Option::replace`], write `Some(val)` into the slot and returns the old value
+    ///
+    /// The old value is returned rather than dropped
+    ///
+    /// # Safety
+    ///
+    /// See [`NonNull::replace`] for safety concerns and examples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    /// use std::rc::Rc;
+    ///
+    /// let val = Rc::new(());
+    /// let mut slot = None;
+    /// let ptr = NonNullMut::from(&mut slot);
+    ///
+    /// unsafe {
+    ///     assert!(ptr.replace_some(val.clone()).is_none());
+    ///     assert_eq!(Rc::strong_count(&val), 2);
+    ///
+    ///     let old = ptr.replace_some(val.clone());
+    ///     assert_eq!(Rc::strong_count(&val), 3);
+    ///     drop(old);
+    ///     assert_eq!(Rc::strong_count(&val), 2);
+    ///
+    ///     drop(ptr.take());
+    /// }
+    /// assert!(slot.is_none());
+    /// assert_eq!(Rc::strong_count(&val), 1);
+    /// ```
+    #[inline]
+    pub unsafe fn replace_some(self, val: T) -> Option<T> {
+        unsafe { self.replace(Some(val)) }
+    }
+}
+
+impl<T> NonNullMut<MaybeUninit<T>> {
+    /// Cast to a pointer of `T`, like [`MaybeUninit::assume_init`] for pointers
+    ///
+    /// This is sound because [`MaybeUninit<T>`] has the same memory layout as `T`,
+    /// no memory is accessed
+    ///
+    /// # Safety
+    ///
+    /// The pointee must be fully initialized before it is read as `T`
+    /// through the returned pointer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut slot = MaybeUninit::<u32>::uninit();
+    /// let uninit = NonNullMut::from(&mut slot);
+    ///
+    /// unsafe {
+    ///     uninit.write(MaybeUninit::new(2));
+    ///     let ptr = uninit.assume_init();
+    ///     assert_eq!(ptr.read(), 2);
+    ///     ptr.write(3);
+    ///     assert_eq!(slot.assume_init(), 3);
+    /// }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const unsafe fn assume_init(self) -> NonNullMut<T> {
+        NonNullMut::from_inner(self.inner.cast())
+    }
+}
+
+impl NonNullMut<c_char> {
+    /// Like [`CStr::from_ptr`], view a NUL-terminated C string
+    ///
+    /// # Safety
+    ///
+    /// See [`CStr::from_ptr`] for safety concerns and examples.
+    ///
+    /// In particular, the memory must be valid for reads up to and including
+    /// the NUL terminator, and must not be mutated for the lifetime `'a`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    /// use std::ffi::CString;
+    ///
+    /// let s = CString::new("hello").unwrap();
+    /// let ptr = NonNullMut::new(s.as_ptr().cast_mut()).unwrap();
+    ///
+    /// let cstr = unsafe { ptr.as_cstr() };
+    /// assert_eq!(cstr.to_bytes(), b"hello");
+    /// assert_eq!(cstr, s.as_c_str());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const unsafe fn as_cstr<'a>(self) -> &'a CStr {
+        unsafe { CStr::from_ptr(self.as_ptr()) }
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T> NonNullMut<*mut T> {
+    /// Like [`AtomicPtr::from_ptr`]
+    ///
+    /// # Safety
+    ///
+    /// See [`AtomicPtr::from_ptr`] for safety concerns and examples.
+    ///
+    /// In particular, the pointee must be aligned to `align_of::<AtomicPtr<T>>()`,
+    /// and must not be accessed non-atomically while the reference is alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let mut x = 2u32;
+    /// let mut slot: *mut u32 = std::ptr::null_mut();
+    /// let ptr = NonNullMut::from(&mut slot);
+    ///
+    /// let atomic = unsafe { ptr.as_atomic_ptr() };
+    /// atomic.store(&mut x, Ordering::Release);
+    /// assert_eq!(atomic.load(Ordering::Acquire), &raw mut x);
+    /// assert_eq!(slot, &raw mut x);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const unsafe fn as_atomic_ptr<'a>(self) -> &'a AtomicPtr<T> {
+        unsafe { AtomicPtr::from_ptr(self.as_ptr()) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> NonNullMut<T> {
+    /// Like [`Box::into_raw`], leak the box into a [`NonNullMut<T>`]
+    ///
+    /// The caller is responsible for the memory previously managed by the box,
+    /// e.g reclaim ownership by [`NonNullMut::into_box`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    /// use std::cell::Cell;
+    ///
+    /// struct Foo<'a>(&'a Cell<u32>);
+    /// impl Drop for Foo<'_> {
+    ///     fn drop(&mut self) {
+    ///         self.0.set(self.0.get() + 1);
+    ///     }
+    /// }
     ///
-    /// # Safety
+    /// let drops = Cell::new(0);
+    /// let ptr = NonNullMut::from_box(Box::new(Foo(&drops)));
+    /// assert_eq!(drops.get(), 0);
     ///
-    /// See [`NonNull::read_volatile`] for safety concerns and examples.
+    /// let b = unsafe { ptr.into_box() };
+    /// assert_eq!(drops.get(), 0);
+    /// drop(b);
+    /// assert_eq!(drops.get(), 1);
+    /// ```
     #[inline]
-    pub unsafe fn read_volatile(self) -> T
-    where
-        T: Sized,
-    {
-        unsafe { self.inner.read_volatile() }
+    #[must_use = "losing the pointer will leak memory"]
+    pub fn from_box(b: Box<T>) -> Self {
+        unsafe { Self::new_unchecked(Box::into_raw(b)) }
     }
 
-    /// Like [`NonNull::read_unaligned`]
+    /// Like [`Box::from_raw`], reclaim ownership of the pointee
     ///
     /// # Safety
     ///
-    /// See [`NonNull::read_unaligned`] for safety concerns and examples.
+    /// See [`Box::from_raw`] for safety concerns and examples.
+    ///
+    /// The pointer must not be used after this call, and the box must not be
+    /// reconstructed twice from the same pointer, otherwise a double free occurs.
     #[inline]
-    pub const unsafe fn read_unaligned(self) -> T
-    where
-        T: Sized,
-    {
-        unsafe { self.inner.read_unaligned() }
+    pub unsafe fn into_box(self) -> Box<T> {
+        unsafe { Box::from_raw(self.as_ptr()) }
     }
 
-    /// Like [`NonNull::copy_to`]
+    /// Like [`NonNullMut::into_box`], but validate the pointer first
+    ///
+    /// In debug builds, panics if the pointer is not aligned for `T`,
+    /// or if it is [dangling](NonNullMut::is_dangling) and `T` is not zero-sized
     ///
     /// # Safety
     ///
-    /// See [`NonNull::copy_to`] for safety concerns and examples.
-    #[inline(always)]
-    pub const unsafe fn copy_to(self, dest: NonNull<T>, count: usize)
-    where
-        T: Sized,
-    {
-        unsafe { self.inner.copy_to(dest, count) }
-    }
-
-    /// Like [`NonNull::copy_to_nonoverlapping`]
+    /// See [`NonNullMut::into_box`] for safety concerns,
+    /// the checks cannot verify the ownership and the allocator origin of the pointer.
     ///
-    /// # Safety
+    /// # Examples
     ///
-    /// See [`NonNull::copy_to_nonoverlapping`] for safety concerns and examples.
-    #[inline(always)]
-    pub const unsafe fn copy_to_nonoverlapping(self, dest: NonNull<T>, count: usize)
-    where
-        T: Sized,
-    {
-        unsafe { self.inner.copy_to_nonoverlapping(dest, count) }
-    }
-
-    /// Like [`NonNull::copy_from`]
+    /// ```
+    /// use nonnull_mut::NonNullMut;
     ///
-    /// # Safety
+    /// let ptr = NonNullMut::from_box(Box::new(2u32));
+    /// assert_eq!(*unsafe { ptr.into_box_checked() }, 2);
     ///
-    /// See [`NonNull::copy_from`] for safety concerns and examples.
-    #[inline(always)]
-    pub const unsafe fn copy_from(self, src: NonNull<T>, count: usize)
-    where
-        T: Sized,
-    {
-        unsafe { self.inner.copy_from(src, count) }
-    }
-
-    /// Like [`NonNull::copy_from_nonoverlapping`]
+    /// let ptr = NonNullMut::from_box(Box::new(()));
+    /// assert_eq!(*unsafe { ptr.into_box_checked() }, ());
+    /// ```
     ///
-    /// # Safety
+    /// In debug builds, a misaligned pointer panics
     ///
-    /// See [`NonNull::copy_from_nonoverlapping`] for safety concerns and examples.
-    #[inline(always)]
-    pub const unsafe fn copy_from_nonoverlapping(self, src: NonNull<T>, count: usize)
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    /// use std::{mem, panic};
+    ///
+    /// let buf = NonNullMut::from_box(Box::new([0u32; 2]));
+    /// let ptr: NonNullMut<u32> = unsafe { buf.byte_add(1) }.cast().into();
+    ///
+    /// if cfg!(debug_assertions) {
+    ///     let result = panic::catch_unwind(|| unsafe { ptr.into_box_checked() });
+    ///     // never drop a box built from the invalid pointer
+    ///     assert!(result.map(mem::forget).is_err());
+    /// }
+    /// drop(unsafe { buf.into_box() });
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub unsafe fn into_box_checked(self) -> Box<T>
     where
         T: Sized,
     {
-        unsafe { self.inner.copy_from_nonoverlapping(src, count) }
+        debug_assert!(self.is_aligned(), "into_box_checked: pointer is misaligned");
+        debug_assert!(
+            size_of::<T>() == 0 || !self.is_dangling(),
+            "into_box_checked: pointer is dangling"
+        );
+        unsafe { self.into_box() }
     }
 
-    /// Like [`NonNull::drop_in_place`]
+    /// Allocate uninitialized memory for `T` by the global allocator
     ///
-    /// # Safety
+    /// Zero-sized `T` does not call the allocator, and returns [`NonNullMut::dangling`]
     ///
-    /// See [`NonNull::drop_in_place`] for safety concerns and examples.
-    #[inline(always)]
-    pub unsafe fn drop_in_place(self) {
-        unsafe { self.inner.drop_in_place() }
-    }
-
-    /// Like [`NonNull::write`]
+    /// On allocation error, calls [`handle_alloc_error`]
     ///
-    /// # Safety
+    /// # Examples
     ///
-    /// See [`NonNull::write`] for safety concerns and examples.
-    #[inline(always)]
-    pub const unsafe fn write(self, val: T)
-    where
-        T: Sized,
-    {
-        unsafe { self.inner.write(val) }
-    }
-
-    /// Like [`NonNull::write_bytes`]
+    /// ```
+    /// use nonnull_mut::NonNullMut;
     ///
-    /// # Safety
+    /// let ptr = NonNullMut::<u64>::alloc_uninit();
+    /// assert!(ptr.is_aligned());
     ///
-    /// See [`NonNull::write_bytes`] for safety concerns and examples.
-    #[inline(always)]
-    pub const unsafe fn write_bytes(self, val: u8, count: usize)
+    /// unsafe {
+    ///     ptr.write(2);
+    ///     assert_eq!(ptr.read(), 2);
+    ///     ptr.dealloc();
+    /// }
+    /// ```
+    #[must_use = "losing the pointer will leak memory"]
+    pub fn alloc_uninit() -> Self
     where
         T: Sized,
     {
-        unsafe { self.inner.write_bytes(val, count) }
+        let layout = Layout::new::<T>();
+        if layout.size() == 0 {
+            return Self::dangling();
+        }
+        let ptr = unsafe { alloc(layout) };
+        match Self::new(ptr.cast()) {
+            Some(ptr) => ptr,
+            None => handle_alloc_error(layout),
+        }
     }
 
-    /// Like [`NonNull::write_volatile`]
+    /// Deallocate the memory allocated by [`NonNullMut::alloc_uninit`]
+    ///
+    /// The pointee is not dropped, see [`NonNullMut::drop_in_place`]
     ///
     /// # Safety
     ///
-    /// See [`NonNull::write_volatile`] for safety concerns and examples.
-    #[inline(always)]
-    pub unsafe fn write_volatile(self, val: T)
+    /// The pointer must be allocated by [`NonNullMut::alloc_uninit`] for the same `T`,
+    /// and not be deallocated yet.
+    pub unsafe fn dealloc(self)
     where
         T: Sized,
     {
-        unsafe { self.inner.write_volatile(val) }
+        let layout = Layout::new::<T>();
+        if layout.size() != 0 {
+            unsafe { dealloc(self.as_ptr().cast(), layout) }
+        }
     }
+}
 
-    /// Like [`NonNull::write_unaligned`]
+#[cfg(feature = "alloc")]
+impl<T> NonNullMut<[T]> {
+    /// Allocate uninitialized memory for `[T]` of `len` elements by the global allocator
     ///
-    /// # Safety
+    /// Zero-sized layout does not call the allocator,
+    /// and returns a slice pointer with [`NonNullMut::dangling`] data pointer
     ///
-    /// See [`NonNull::write_unaligned`] for safety concerns and examples.
-    #[inline(always)]
-    pub const unsafe fn write_unaligned(self, val: T)
-    where
-        T: Sized,
-    {
-        unsafe { self.inner.write_unaligned(val) }
-    }
-
-    /// Like [`NonNull::replace`]
+    /// On allocation error, calls [`handle_alloc_error`]
     ///
-    /// # Safety
+    /// # Panics
     ///
-    /// See [`NonNull::replace`] for safety concerns and examples.
-    #[inline(always)]
-    pub unsafe fn replace(self, src: T) -> T
-    where
-        T: Sized,
-    {
-        unsafe { self.inner.replace(src) }
+    /// Panics if the layout size exceeds [`isize::MAX`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nonnull_mut::NonNullMut;
+    ///
+    /// let ptr = NonNullMut::<[u32]>::alloc_slice_uninit(0);
+    /// assert!(ptr.is_empty());
+    /// assert_eq!(ptr.as_non_null_ptr(), NonNullMut::dangling());
+    /// unsafe { ptr.dealloc_slice() };
+    ///
+    /// let ptr = NonNullMut::<[u32]>::alloc_slice_uninit(1);
+    /// assert_eq!(ptr.len(), 1);
+    /// unsafe {
+    ///     ptr.as_non_null_ptr().write(2);
+    ///     assert_eq!(ptr.as_ref(), &[2]);
+    ///     ptr.dealloc_slice();
+    /// }
+    ///
+    /// let ptr = NonNullMut::<[u32]>::alloc_slice_uninit(100);
+    /// assert_eq!(ptr.len(), 100);
+    /// unsafe {
+    ///     for i in 0..100 {
+    ///         ptr.as_non_null_ptr().add(i).write(i as u32);
+    ///     }
+    ///     assert!(ptr.as_ref().iter().copied().eq(0..100));
+    ///     ptr.dealloc_slice();
+    /// }
+    /// ```
+    #[must_use = "losing the pointer will leak memory"]
+    #[track_caller]
+    pub fn alloc_slice_uninit(len: usize) -> Self {
+        let layout = Layout::array::<T>(len).expect("allocation too large");
+        if layout.size() == 0 {
+            return Self::slice_from_raw_parts(NonNullMut::dangling(), len);
+        }
+        let ptr = unsafe { alloc(layout) };
+        match NonNullMut::new(ptr.cast()) {
+            Some(data) => Self::slice_from_raw_parts(data, len),
+            None => handle_alloc_error(layout),
+        }
     }
 
-    /// Like [`NonNull::swap`]
+    /// Deallocate the memory allocated by [`NonNullMut::alloc_slice_uninit`]
+    ///
+    /// The elements are not dropped, see [`NonNullMut::drop_in_place`]
     ///
     /// # Safety
     ///
-    /// See [`NonNull::swap`] for safety concerns and examples.
-    #[inline(always)]
-    pub const unsafe fn swap(self, with: NonNull<T>)
-    where
-        T: Sized,
-    {
-        unsafe { self.inner.swap(with) }
-    }
-
-    /// Like [`NonNull::align_offset`]
-    #[inline]
-    #[must_use]
-    pub fn align_offset(self, align: usize) -> usize
-    where
-        T: Sized,
-    {
-        self.inner.align_offset(align)
-    }
-
-    /// Like [`NonNull::is_aligned`]
-    #[inline]
-    #[must_use]
-    pub fn is_aligned(self) -> bool
-    where
-        T: Sized,
-    {
-        self.inner.is_aligned()
+    /// The pointer must be allocated by [`NonNullMut::alloc_slice_uninit`]
+    /// for the same `T` and length, and not be deallocated yet.
+    pub unsafe fn dealloc_slice(self) {
+        let layout = unsafe { Layout::array::<T>(self.len()).unwrap_unchecked() };
+        if layout.size() != 0 {
+            unsafe { dealloc(self.as_mut_ptr().cast(), layout) }
+        }
     }
 }
 
-impl<T> NonNullMut<[T]> {
-    /// Like [`NonNull::slice_from_raw_parts`]
-    #[inline]
-    #[must_use]
-    pub const fn slice_from_raw_parts(data: NonNull<T>, len: usize) -> Self {
-        Self::from_inner(NonNull::slice_from_raw_parts(data, len))
-    }
-
-    /// Like [`NonNull::len`]
-    #[inline]
-    #[must_use]
-    pub const fn len(self) -> usize {
-        self.inner.len()
-    }
+/// Like [`NonNull`], allow unsizing coercions
+///
+/// # Examples
+///
+/// ```
+/// use nonnull_mut::NonNullMut;
+/// use std::fmt::Debug;
+///
+/// let mut a = [1u8, 2, 3];
+/// let ptr: NonNullMut<[u8; 3]> = NonNullMut::from(&mut a);
+///
+/// let slice: NonNullMut<[u8]> = ptr;
+/// assert_eq!(slice.len(), 3);
+///
+/// let dyn_ptr: NonNullMut<dyn Debug> = ptr;
+/// assert_eq!(format!("{:?}", unsafe { dyn_ptr.as_ref() }), "[1, 2, 3]");
+/// ```
+#[cfg(feature = "coerce-unsized")]
+impl<T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<NonNullMut<U>> for NonNullMut<T> {}
 
-    /// Like [`NonNull::is_empty`]
-    #[inline]
-    #[must_use]
-    pub const fn is_empty(self) -> bool {
-        self.inner.is_empty()
-    }
-}
+#[cfg(feature = "coerce-unsized")]
+impl<T: ?Sized + Unsize<U>, U: ?Sized> DispatchFromDyn<NonNullMut<U>> for NonNullMut<T> {}
 
 impl<T: ?Sized> Clone for NonNullMut<T> {
     #[inline(always)]
@@ -490,9 +3918,59 @@ impl<T: ?Sized> Clone for NonNullMut<T> {
 
 impl<T: ?Sized> Copy for NonNullMut<T> {}
 
+/// Unlike `*mut T`, this is [`Send`] with the `send-sync` feature,
+/// the caller is responsible for synchronizing access to the pointee
+/// when sending the pointer to another thread.
+///
+/// # Examples
+///
+/// ```
+/// use nonnull_mut::NonNullMut;
+///
+/// static mut X: u32 = 0;
+///
+/// let ptr = NonNullMut::new(&raw mut X).unwrap();
+/// std::thread::spawn(move || unsafe { ptr.write(2) }).join().unwrap();
+/// assert_eq!(unsafe { ptr.read() }, 2);
+/// ```
+#[cfg(feature = "send-sync")]
+unsafe impl<T: ?Sized> Send for NonNullMut<T> {}
+
+/// Unlike `*mut T`, this is [`Sync`] with the `send-sync` feature,
+/// the caller is responsible for synchronizing access to the pointee
+/// when sharing the pointer between threads.
+#[cfg(feature = "send-sync")]
+unsafe impl<T: ?Sized> Sync for NonNullMut<T> {}
+
+/// Format as [`fmt::Pointer`]
+///
+/// With `ptr-metadata` feature, the alternate form (`{:#?}`) also
+/// includes the pointer metadata, e.g slice length
+///
+/// # Examples
+///
+/// ```
+/// use nonnull_mut::NonNullMut;
+///
+/// let mut a = [0u8; 3];
+/// let ptr = NonNullMut::<[u8]>::from(&mut a[..]);
+///
+/// assert_eq!(format!("{ptr:?}"), format!("{ptr:p}"));
+///
+/// #[cfg(feature = "ptr-metadata")]
+/// assert!(format!("{ptr:#?}").contains("metadata: 3"));
+/// ```
 impl<T: ?Sized> fmt::Debug for NonNullMut<T> {
     #[inline(always)]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "ptr-metadata")]
+        if f.alternate() {
+            return f
+                .debug_struct("NonNullMut")
+                .field("addr", &format_args!("{:p}", self.as_ptr()))
+                .field("metadata", &self.metadata())
+                .finish();
+        }
         fmt::Pointer::fmt(&self.as_ptr(), f)
     }
 }
@@ -513,6 +3991,52 @@ impl<T: ?Sized> PartialEq for NonNullMut<T> {
     }
 }
 
+/// # Examples
+///
+/// ```
+/// use nonnull_mut::NonNullMut;
+/// use std::ptr::NonNull;
+///
+/// let mut a = [1u32, 2];
+/// let ptr = NonNullMut::new(a.as_mut_ptr()).unwrap();
+///
+/// assert_eq!(ptr, NonNull::new(a.as_mut_ptr()).unwrap());
+/// assert_eq!(NonNull::new(a.as_mut_ptr()).unwrap(), ptr);
+/// assert_ne!(ptr, a[1..].as_mut_ptr());
+/// assert_ne!(a[1..].as_mut_ptr(), ptr);
+/// ```
+#[allow(ambiguous_wide_pointer_comparisons)]
+impl<T: ?Sized> PartialEq<NonNull<T>> for NonNullMut<T> {
+    #[inline]
+    fn eq(&self, other: &NonNull<T>) -> bool {
+        self.as_ptr() == other.as_ptr()
+    }
+}
+
+#[allow(ambiguous_wide_pointer_comparisons)]
+impl<T: ?Sized> PartialEq<NonNullMut<T>> for NonNull<T> {
+    #[inline]
+    fn eq(&self, other: &NonNullMut<T>) -> bool {
+        self.as_ptr() == other.as_ptr()
+    }
+}
+
+#[allow(ambiguous_wide_pointer_comparisons)]
+impl<T: ?Sized> PartialEq<*mut T> for NonNullMut<T> {
+    #[inline]
+    fn eq(&self, other: &*mut T) -> bool {
+        self.as_ptr() == *other
+    }
+}
+
+#[allow(ambiguous_wide_pointer_comparisons)]
+impl<T: ?Sized> PartialEq<NonNullMut<T>> for *mut T {
+    #[inline]
+    fn eq(&self, other: &NonNullMut<T>) -> bool {
+        *self == other.as_ptr()
+    }
+}
+
 #[allow(ambiguous_wide_pointer_comparisons)]
 impl<T: ?Sized> Ord for NonNullMut<T> {
     #[inline]
@@ -530,6 +4054,25 @@ impl<T: ?Sized> PartialOrd for NonNullMut<T> {
     }
 }
 
+/// Hash both the address and the metadata (e.g slice length or vtable),
+/// consistent with [`PartialEq`], like the [`Hash`](hash::Hash) impl of `*mut T`
+///
+/// Use [`ByAddr`] to hash by the address only
+///
+/// # Examples
+///
+/// ```
+/// use nonnull_mut::{ByAddr, NonNullMut};
+/// use std::hash::{BuildHasher, RandomState};
+///
+/// let mut a = [0u8; 4];
+/// let ptr = NonNullMut::<[u8]>::from(&mut a[..]);
+/// let (head, _) = unsafe { ptr.split_at_mut_unchecked(2) };
+///
+/// let state = RandomState::new();
+/// assert_ne!(state.hash_one(ptr), state.hash_one(head));
+/// assert_eq!(state.hash_one(ByAddr(ptr)), state.hash_one(ByAddr(head)));
+/// ```
 #[allow(ambiguous_wide_pointer_comparisons)]
 impl<T: ?Sized> hash::Hash for NonNullMut<T> {
     #[inline]
@@ -538,6 +4081,35 @@ impl<T: ?Sized> hash::Hash for NonNullMut<T> {
     }
 }
 
+/// Borrow as the inner [`NonNull<T>`],
+/// the [`Hash`](hash::Hash), [`Eq`] and [`Ord`] impls agree with [`NonNull<T>`]
+///
+/// # Examples
+///
+/// ```
+/// use nonnull_mut::NonNullMut;
+/// use std::collections::{BTreeSet, HashSet};
+///
+/// let mut a = [0u32; 3];
+/// let [x, y, z] = &mut a;
+/// let (x, y, z) = (NonNullMut::from(x), NonNullMut::from(y), NonNullMut::from(z));
+///
+/// let btree = BTreeSet::from([x, y]);
+/// assert_eq!(btree.get(&x.as_inner()), Some(&x));
+/// assert!(btree.contains(&y.as_inner()));
+/// assert!(!btree.contains(&z.as_inner()));
+///
+/// let hash = HashSet::from([x, y]);
+/// assert_eq!(hash.get(&y.as_inner()), Some(&y));
+/// assert!(!hash.contains(&z.as_inner()));
+/// ```
+impl<T: ?Sized> Borrow<NonNull<T>> for NonNullMut<T> {
+    #[inline]
+    fn borrow(&self) -> &NonNull<T> {
+        &self.inner
+    }
+}
+
 impl<T: ?Sized> From<&mut T> for NonNullMut<T> {
     #[inline]
     fn from(r: &mut T) -> Self {
@@ -551,3 +4123,230 @@ impl<T: ?Sized> From<&T> for NonNullMut<T> {
         NonNullMut::from_inner(r.into())
     }
 }
+
+/// # Examples
+///
+/// ```
+/// use nonnull_mut::NonNullMut;
+///
+/// let mut a = [0u8; 16];
+/// let ptr = NonNullMut::<[u8; 16]>::from(&mut a);
+/// let slice = NonNullMut::<[u8]>::from(ptr);
+///
+/// assert_eq!(slice.len(), 16);
+/// assert_eq!(slice.addr(), ptr.addr());
+/// ```
+impl<T, const N: usize> From<NonNullMut<[T; N]>> for NonNullMut<[T]> {
+    #[inline]
+    fn from(value: NonNullMut<[T; N]>) -> Self {
+        NonNullMut::slice_from_raw_parts(NonNullMut::from_inner(value.cast()), N)
+    }
+}
+
+/// Serialize as the pointer address, and expose the provenance of the pointer
+///
+/// # Examples
+///
+/// ```
+/// use nonnull_mut::NonNullMut;
+///
+/// let mut x = 0u32;
+/// let ptr = NonNullMut::from(&mut x);
+///
+/// let json = serde_json::to_string(&ptr).unwrap();
+/// assert_eq!(json, ptr.addr().to_string());
+///
+/// let de: NonNullMut<u32> = serde_json::from_str(&json).unwrap();
+/// assert_eq!(de, ptr);
+///
+/// assert!(serde_json::from_str::<NonNullMut<u32>>("0").is_err());
+/// ```
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for NonNullMut<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u64(self.expose_provenance().get() as u64)
+    }
+}
+
+/// Deserialize from the pointer address, zero address is an error
+///
+/// **WARNING**: The deserialized pointer is created by
+/// [`NonNullMut::with_exposed_provenance`], there is no guarantee that it has
+/// the provenance of any allocated object,
+/// dereferencing it is likely to be undefined behavior.
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for NonNullMut<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::{Error, Unexpected};
+
+        let addr = <u64 as serde::Deserialize>::deserialize(deserializer)?;
+        let nonzero = usize::try_from(addr).ok().and_then(NonZeroUsize::new);
+        let addr = nonzero.ok_or_else(|| {
+            Error::invalid_value(Unexpected::Unsigned(addr), &"a non-zero pointer address")
+        })?;
+        Ok(Self::with_exposed_provenance(addr))
+    }
+}
+
+/// The bytes of [`NonNullMut<T>`] is the pointer address,
+/// but it is not [`Zeroable`](bytemuck::Zeroable)
+///
+/// # Examples
+///
+/// ```
+/// use nonnull_mut::NonNullMut;
+///
+/// let mut a = [1u32, 2];
+/// let [x, y] = &mut a;
+/// let ptrs = [NonNullMut::from(x), NonNullMut::from(y)];
+///
+/// let bytes: &[u8] = bytemuck::cast_slice(&ptrs);
+/// let size = size_of::<usize>();
+/// assert_eq!(bytes.len(), size * 2);
+///
+/// for (chunk, ptr) in bytes.chunks(size).zip(ptrs) {
+///     let addr = usize::from_ne_bytes(chunk.try_into().unwrap());
+///     assert_eq!(addr, ptr.addr().get());
+/// }
+/// ```
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: 'static> bytemuck::NoUninit for NonNullMut<T> {}
+
+/// Generate a non-zero address, the pointer is created by
+/// [`NonNullMut::with_exposed_provenance`]
+///
+/// **WARNING**: The generated pointer is only suitable for address arithmetic,
+/// dereferencing it is likely to be undefined behavior.
+///
+/// # Examples
+///
+/// ```
+/// use arbitrary::{Arbitrary, Unstructured};
+/// use nonnull_mut::NonNullMut;
+///
+/// let data = 0x1000usize.to_ne_bytes();
+/// let mut u = Unstructured::new(&data);
+/// let ptr = NonNullMut::<u32>::arbitrary(&mut u).unwrap();
+/// assert_eq!(ptr.addr().get(), 0x1000);
+///
+/// let data = 0usize.to_ne_bytes();
+/// let mut u = Unstructured::new(&data);
+/// assert!(NonNullMut::<u32>::arbitrary(&mut u).is_err());
+/// ```
+#[cfg(feature = "arbitrary")]
+impl<'a, T> arbitrary::Arbitrary<'a> for NonNullMut<T> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let addr = NonZeroUsize::new(usize::arbitrary(u)?);
+        let addr = addr.ok_or(arbitrary::Error::IncorrectFormat)?;
+        Ok(Self::with_exposed_provenance(addr))
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <usize as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
+/// The archived form of [`NonNullMut<T>`], created by the `rkyv` feature
+///
+/// It stores the pointer address as an archived `u64`,
+/// the pointer is archived by [`NonNullMut::expose_provenance`]
+/// and deserialized by [`NonNullMut::with_exposed_provenance`], zero address is an error
+///
+/// **WARNING**: The archived address is only meaningful in the process that archived it,
+/// there is no guarantee that the deserialized pointer has
+/// the provenance of any allocated object,
+/// dereferencing it is likely to be undefined behavior.
+///
+/// # Examples
+///
+/// ```
+/// use nonnull_mut::NonNullMut;
+/// use rkyv::rancor::Error;
+///
+/// let mut x = 0u32;
+/// let ptr = NonNullMut::from(&mut x);
+///
+/// let bytes = rkyv::to_bytes::<Error>(&ptr).unwrap();
+/// let archived = rkyv::access::<rkyv::Archived<NonNullMut<u32>>, Error>(&bytes).unwrap();
+/// assert_eq!(archived.addr(), ptr.addr().get() as u64);
+///
+/// let de = rkyv::from_bytes::<NonNullMut<u32>, Error>(&bytes).unwrap();
+/// assert_eq!(de, ptr);
+///
+/// let bytes = rkyv::to_bytes::<Error>(&0u64).unwrap();
+/// assert!(rkyv::from_bytes::<NonNullMut<u32>, Error>(&bytes).is_err());
+/// ```
+#[cfg(feature = "rkyv")]
+#[derive(rkyv::Portable, rkyv::bytecheck::CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck)]
+#[repr(transparent)]
+pub struct ArchivedNonNullMut(rkyv::Archived<u64>);
+
+#[cfg(feature = "rkyv")]
+unsafe impl rkyv::traits::NoUndef for ArchivedNonNullMut {}
+
+#[cfg(feature = "rkyv")]
+impl ArchivedNonNullMut {
+    /// The archived pointer address
+    #[inline]
+    #[must_use]
+    pub fn addr(&self) -> u64 {
+        self.0.to_native()
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl fmt::Debug for ArchivedNonNullMut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ArchivedNonNullMut")
+            .field(&format_args!("{:#x}", self.addr()))
+            .finish()
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<T> rkyv::Archive for NonNullMut<T> {
+    type Archived = ArchivedNonNullMut;
+    type Resolver = ();
+
+    #[inline]
+    fn resolve(&self, _: Self::Resolver, out: rkyv::Place<Self::Archived>) {
+        let addr = self.expose_provenance().get() as u64;
+        out.write(ArchivedNonNullMut(addr.into()));
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<T, S> rkyv::Serialize<S> for NonNullMut<T>
+where
+    S: rkyv::rancor::Fallible + ?Sized,
+{
+    #[inline]
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<T, D> rkyv::Deserialize<NonNullMut<T>, D> for ArchivedNonNullMut
+where
+    D: rkyv::rancor::Fallible + ?Sized,
+    D::Error: rkyv::rancor::Source,
+{
+    fn deserialize(&self, _: &mut D) -> Result<NonNullMut<T>, D::Error> {
+        use rkyv::rancor::Source;
+
+        let nonzero = usize::try_from(self.addr())
+            .ok()
+            .and_then(NonZeroUsize::new);
+        let addr = nonzero.ok_or_else(|| D::Error::new(NullPtrError))?;
+        Ok(NonNullMut::with_exposed_provenance(addr))
+    }
+}
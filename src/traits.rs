@@ -0,0 +1,101 @@
+use core::ptr::NonNull;
+
+use crate::NonNullMut;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Pointer-like types that can be viewed as a [`NonNull`]
+///
+/// This trait is sealed and cannot be implemented outside this crate
+///
+/// For `&T` and `&mut T`, the pointer is derived from a shared reborrow,
+/// it must not be written through
+///
+/// # Examples
+///
+/// ```
+/// use nonnull_mut::{AsNonNull, NonNullMut};
+/// use std::ptr::NonNull;
+///
+/// fn addr_of<P: AsNonNull<Pointee = u32>>(ptr: P) -> usize {
+///     ptr.as_non_null().addr().get()
+/// }
+///
+/// let mut x = 2u32;
+/// let addr = NonNullMut::from(&mut x).addr().get();
+///
+/// assert_eq!(addr_of(NonNullMut::from(&mut x)), addr);
+/// assert_eq!(addr_of(NonNull::from(&mut x)), addr);
+/// assert_eq!(addr_of(&x), addr);
+/// assert_eq!(addr_of(&mut x), addr);
+/// ```
+///
+/// Cannot be implemented for other types
+///
+/// ```compile_fail
+/// use nonnull_mut::AsNonNull;
+/// use std::ptr::NonNull;
+///
+/// struct Foo;
+///
+/// impl AsNonNull for Foo {
+///     type Pointee = Foo;
+///
+///     fn as_non_null(&self) -> NonNull<Foo> {
+///         NonNull::from(self)
+///     }
+/// }
+/// ```
+pub trait AsNonNull: private::Sealed {
+    /// The pointed-to type
+    type Pointee: ?Sized;
+
+    /// View `self` as a [`NonNull`]
+    fn as_non_null(&self) -> NonNull<Self::Pointee>;
+}
+
+impl<T: ?Sized> private::Sealed for NonNullMut<T> {}
+
+impl<T: ?Sized> AsNonNull for NonNullMut<T> {
+    type Pointee = T;
+
+    #[inline]
+    fn as_non_null(&self) -> NonNull<T> {
+        self.as_inner()
+    }
+}
+
+impl<T: ?Sized> private::Sealed for NonNull<T> {}
+
+impl<T: ?Sized> AsNonNull for NonNull<T> {
+    type Pointee = T;
+
+    #[inline]
+    fn as_non_null(&self) -> NonNull<T> {
+        *self
+    }
+}
+
+impl<T: ?Sized> private::Sealed for &T {}
+
+impl<T: ?Sized> AsNonNull for &T {
+    type Pointee = T;
+
+    #[inline]
+    fn as_non_null(&self) -> NonNull<T> {
+        NonNull::from(*self)
+    }
+}
+
+impl<T: ?Sized> private::Sealed for &mut T {}
+
+impl<T: ?Sized> AsNonNull for &mut T {
+    type Pointee = T;
+
+    #[inline]
+    fn as_non_null(&self) -> NonNull<T> {
+        NonNull::from(&**self)
+    }
+}